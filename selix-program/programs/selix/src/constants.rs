@@ -6,6 +6,9 @@ pub const LISTING_SEED: &[u8] = b"listing";
 pub const VAULT_SEED: &[u8] = b"vault";
 pub const USER_PROFILE_SEED: &[u8] = b"user_profile";
 pub const WHITELIST_SEED: &[u8] = b"whitelist";
+pub const OFFER_SEED: &[u8] = b"offer";
+pub const ORDER_NONCES_SEED: &[u8] = b"order_nonces";
+pub const ORDER_AUTHORITY_SEED: &[u8] = b"order_authority";
 
 // Platform Defaults
 pub const DEFAULT_FEE_BPS: u16 = 25; // 0.25%
@@ -15,11 +18,45 @@ pub const MAX_LISTING_DURATION: i64 = 2_592_000; // 30 days
 pub const DEFAULT_LISTING_DURATION: i64 = 86_400; // 1 day
 pub const MIN_TRADE_AMOUNT: u64 = 1000; // Prevent dust
 pub const MAX_LISTINGS_PER_USER: u16 = 100;
+pub const MAX_ALLOWED_TAKERS: usize = 5; // Private listing taker set
+pub const DEFAULT_MAX_GTC_LISTINGS_PER_USER: u16 = 10;
+pub const MAX_BATCH_LISTINGS: usize = 10; // Fits one transaction with a lookup table
 
 // Slippage
 pub const DEFAULT_SLIPPAGE_BPS: u16 = 100; // 1%
 pub const MAX_SLIPPAGE_BPS: u16 = 1000; // 10%
 
+// Routing
+pub const MAX_ROUTE_HOPS: usize = 4;
+pub const MAX_SWEEP_LISTINGS: usize = 8;
+
+// Oracle
+pub const MAX_ORACLE_PRICE_AGE_SECONDS: i64 = 60;
+pub const MAX_ORACLE_CONFIDENCE_BPS: u64 = 200; // 2% of price
+pub const MAX_ORACLE_PREMIUM_BPS: i16 = 5000; // +/-50%
+
+// Signed Orders
+pub const SIGNED_ORDER_DOMAIN: &[u8] = b"selix:signed_order:v1";
+pub const ORDER_NONCE_BITMAP_WORDS: usize = 16; // 1024 nonces above the floor
+pub const RFQ_QUOTE_DOMAIN: &[u8] = b"selix:rfq_quote:v1";
+pub const MAX_RFQ_QUOTE_SLOTS: u64 = 150; // ~1 minute
+
+// Mint Feature Policy (bitmask of risky mint features)
+pub const MINT_FEATURE_PERMANENT_DELEGATE: u8 = 1 << 0;
+pub const MINT_FEATURE_NON_TRANSFERABLE: u8 = 1 << 1;
+pub const MINT_FEATURE_DEFAULT_FROZEN: u8 = 1 << 2;
+pub const MINT_FEATURE_FREEZE_AUTHORITY: u8 = 1 << 3;
+pub const MINT_FEATURE_CLOSE_AUTHORITY: u8 = 1 << 4;
+pub const MINT_FEATURE_ALL: u8 = (1 << 5) - 1;
+pub const DEFAULT_REJECTED_MINT_FEATURES: u8 =
+    MINT_FEATURE_PERMANENT_DELEGATE | MINT_FEATURE_NON_TRANSFERABLE | MINT_FEATURE_DEFAULT_FROZEN;
+pub const DEFAULT_FLAGGED_MINT_FEATURES: u8 =
+    MINT_FEATURE_FREEZE_AUTHORITY | MINT_FEATURE_CLOSE_AUTHORITY;
+
+// Cancel Epochs (profiles start at 1, so listings recorded at epoch 0
+// predate the kill switch and can be filled without the maker profile)
+pub const INITIAL_CANCEL_EPOCH: u64 = 1;
+
 // Rewards
 pub const CLOSER_REWARD_LAMPORTS: u64 = 1_000_000; // 0.001 SOL for closing expired
 
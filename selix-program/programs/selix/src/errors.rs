@@ -21,6 +21,12 @@ pub enum SelixError {
     #[msg("Invalid duration bounds: min must be less than max")]
     InvalidDurationBounds,
 
+    #[msg("Good-till-cancelled listings are disabled")]
+    GoodTillCancelledDisabled,
+
+    #[msg("Invalid mint feature policy: unknown feature bits")]
+    InvalidMintFeaturePolicy,
+
     // Listing Errors (6100-6199)
     #[msg("Invalid amount: must be greater than zero")]
     InvalidAmount,
@@ -58,6 +64,39 @@ pub enum SelixError {
     #[msg("Listing not expired yet")]
     ListingNotExpired,
 
+    #[msg("Listing is good-till-cancelled and has no expiry")]
+    ListingHasNoExpiry,
+
+    #[msg("Withdraw amount must leave inventory in the listing: cancel instead")]
+    WithdrawAmountTooLarge,
+
+    #[msg("Too many allowed takers for a private listing")]
+    TooManyAllowedTakers,
+
+    #[msg("Invalid start time: must be in the future and within the maximum listing duration")]
+    InvalidStartTime,
+
+    #[msg("Listing has not reached its start time")]
+    ListingNotStarted,
+
+    #[msg("Invalid display amount: must be below the listing amount and cover the minimum fill")]
+    InvalidDisplayAmount,
+
+    #[msg("Invalid release schedule")]
+    InvalidReleaseSchedule,
+
+    #[msg("Invalid auction parameters")]
+    InvalidAuctionParameters,
+
+    #[msg("Operation not supported for this listing kind")]
+    UnsupportedListingKind,
+
+    #[msg("Invalid listing batch: expected one account group per listing")]
+    InvalidListingBatch,
+
+    #[msg("Listing was invalidated by the maker's cancel epoch")]
+    ListingCancelledByEpoch,
+
     // Trading Errors (6200-6299)
     #[msg("Slippage tolerance exceeded")]
     SlippageExceeded,
@@ -65,6 +104,15 @@ pub enum SelixError {
     #[msg("Fill amount below minimum")]
     FillAmountTooSmall,
 
+    #[msg("Listing is all-or-none: partial fills are not allowed")]
+    PartialFillNotAllowed,
+
+    #[msg("Swap amount exceeds the unlocked portion of the listing")]
+    AmountNotUnlocked,
+
+    #[msg("Swap amount exceeds the displayed size of the listing")]
+    AmountExceedsDisplayed,
+
     #[msg("Insufficient maker balance")]
     InsufficientMakerBalance,
 
@@ -77,6 +125,24 @@ pub enum SelixError {
     #[msg("Cannot swap with own listing")]
     CannotSwapOwnListing,
 
+    #[msg("Taker is not allowed to fill this private listing")]
+    TakerNotAllowed,
+
+    #[msg("Listings do not trade opposite sides of the same pair")]
+    ListingPairMismatch,
+
+    #[msg("Listing prices do not cross")]
+    ListingsDoNotCross,
+
+    #[msg("Invalid route: hops must chain each output into the next input")]
+    InvalidRoute,
+
+    #[msg("Invalid sweep: listings must be distinct and trade the swept pair")]
+    InvalidSweep,
+
+    #[msg("No listing could be filled within the sweep limits")]
+    NoFillableListings,
+
     // Token Errors (6300-6399)
     #[msg("Token mint not whitelisted")]
     TokenNotWhitelisted,
@@ -93,6 +159,12 @@ pub enum SelixError {
     #[msg("Token account authority mismatch")]
     TokenAccountAuthorityMismatch,
 
+    #[msg("Invalid token extension data")]
+    InvalidTokenExtensionData,
+
+    #[msg("Token mint has a feature rejected by the platform policy")]
+    MintFeatureRejected,
+
     // Math Errors (6400-6499)
     #[msg("Arithmetic overflow")]
     ArithmeticOverflow,
@@ -141,4 +213,77 @@ pub enum SelixError {
 
     #[msg("Account not initialized")]
     AccountNotInitialized,
+
+    // Oracle Errors (6800-6899)
+    #[msg("Invalid or missing price feed account")]
+    InvalidPriceFeed,
+
+    #[msg("Price feed is stale")]
+    StalePrice,
+
+    #[msg("Price feed confidence interval is too wide")]
+    PriceConfidenceTooWide,
+
+    #[msg("Oracle premium or discount out of range")]
+    InvalidOraclePremium,
+
+    #[msg("Invalid trigger condition or price")]
+    InvalidTrigger,
+
+    #[msg("Trigger price has not been reached")]
+    TriggerNotMet,
+
+    // Offer Errors (6900-6999)
+    #[msg("Offer has expired")]
+    OfferExpired,
+
+    #[msg("Offer has not expired yet")]
+    OfferNotExpired,
+
+    #[msg("Offer does not belong to this listing")]
+    OfferListingMismatch,
+
+    // Signed Order Errors (7000-7099)
+    #[msg("Missing or malformed Ed25519 signature instruction")]
+    InvalidSignatureInstruction,
+
+    #[msg("Signature does not match the order or signer")]
+    SignatureMismatch,
+
+    #[msg("Signed order does not match the supplied accounts")]
+    InvalidSignedOrder,
+
+    #[msg("Signed order has expired")]
+    OrderExpired,
+
+    #[msg("Nonce already used or cancelled")]
+    NonceAlreadyUsed,
+
+    #[msg("Nonce too far above the nonce floor")]
+    NonceOutOfRange,
+
+    #[msg("New nonce floor must be above the current floor")]
+    InvalidNonceFloor,
+
+    #[msg("Maker token account has not approved the order authority for this amount")]
+    MissingDelegateApproval,
+
+    #[msg("RFQ quote has expired")]
+    QuoteExpired,
+
+    #[msg("RFQ quote expiry is too far in the future")]
+    QuoteValidityTooLong,
+
+    // NFT Errors (7100-7199)
+    #[msg("NFT listings must trade exactly one token")]
+    InvalidNftAmount,
+
+    #[msg("Mint is not an NFT (0 decimals, supply 1)")]
+    NotAnNft,
+
+    #[msg("Invalid or missing NFT metadata account")]
+    InvalidNftMetadata,
+
+    #[msg("NFT is not a verified member of the collection")]
+    NftNotInCollection,
 }
@@ -1,4 +1,4 @@
-use crate::state::ListingStatus;
+use crate::state::{ListingKind, ListingSide, ListingStatus, SweepSkipReason, TriggerCondition};
 use anchor_lang::prelude::*;
 
 /// Emitted when platform is initialized
@@ -43,6 +43,16 @@ pub struct FeeCollectorUpdated {
     pub timestamp: i64,
 }
 
+/// Emitted when a platform or user profile account is grown to the current layout
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub old_size: u64,
+    pub new_size: u64,
+    pub timestamp: i64,
+}
+
 /// Emitted when a new listing is created
 #[event]
 pub struct ListingCreated {
@@ -50,10 +60,65 @@ pub struct ListingCreated {
     pub maker: Pubkey,
     pub token_mint_source: Pubkey,
     pub token_mint_destination: Pubkey,
+    pub side: ListingSide,
+    pub source_is_nft: bool,
+    pub destination_is_nft: bool,
+    /// Any verified NFT of the destination collection is accepted
+    pub destination_is_collection: bool,
+    /// Visible clip for iceberg listings
     pub amount_source: u64,
+    /// Visible clip for iceberg listings
     pub amount_destination: u64,
+    /// Token-2022 transfer fee withheld on the deposit into the vault
+    pub source_transfer_fee: u64,
+    pub is_iceberg: bool,
     pub min_fill_amount: u64,
+    pub all_or_none: bool,
+    pub allowed_takers: Vec<Pubkey>,
+    pub kind: ListingKind,
+    pub auction_floor_amount_destination: u64,
+    pub auction_step_seconds: i64,
+    pub price_feed: Pubkey,
+    pub price_feed_id: [u8; 32],
+    pub oracle_premium_bps: i16,
+    pub trigger_condition: TriggerCondition,
+    pub trigger_price: i64,
+    pub starts_at: i64,
     pub expires_at: i64,
+    pub good_till_cancelled: bool,
+    pub release_cliff_at: i64,
+    pub release_end_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a listing is created with a mint feature the platform flags
+#[event]
+pub struct ListingMintFlagged {
+    pub listing_id: u64,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    /// MINT_FEATURE_* bits present on the mint and flagged by the platform
+    pub features: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a scheduled listing becomes fillable
+#[event]
+pub struct ListingActivated {
+    pub listing_id: u64,
+    pub maker: Pubkey,
+    pub activated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an oracle trigger moves a pending listing to active
+#[event]
+pub struct ListingTriggered {
+    pub listing_id: u64,
+    pub maker: Pubkey,
+    pub triggered_by: Pubkey,
+    pub oracle_price: i64,
+    pub trigger_price: i64,
     pub timestamp: i64,
 }
 
@@ -67,6 +132,19 @@ pub struct ListingUpdated {
     pub timestamp: i64,
 }
 
+/// Emitted when escrowed inventory is added to or withdrawn from a listing
+#[event]
+pub struct ListingResized {
+    pub listing_id: u64,
+    pub maker: Pubkey,
+    pub is_increase: bool,
+    pub amount_source: u64,
+    pub amount_destination: u64,
+    pub new_amount_source_remaining: u64,
+    pub new_amount_destination_remaining: u64,
+    pub timestamp: i64,
+}
+
 /// Emitted when a listing is cancelled
 #[event]
 pub struct ListingCancelled {
@@ -94,15 +172,207 @@ pub struct SwapExecuted {
     pub taker: Pubkey,
     pub token_mint_source: Pubkey,
     pub token_mint_destination: Pubkey,
+    pub side: ListingSide,
     pub amount_source: u64,
     pub amount_destination: u64,
+    /// Quote token amount of the fill (destination for asks, source for bids)
+    pub quote_volume: u64,
+    /// Destination per source the fill cleared at (scaled by BPS_DENOMINATOR)
+    pub clearing_rate: u64,
     pub fee_amount: u64,
+    /// Token-2022 transfer fee withheld from the source tokens sent to the taker
+    pub source_transfer_fee: u64,
+    /// Token-2022 transfer fee withheld from the destination tokens paid out
+    pub destination_transfer_fee: u64,
     pub is_partial: bool,
+    /// Visible clip remaining for iceberg listings
     pub remaining_source: u64,
     pub new_status: ListingStatus,
     pub timestamp: i64,
 }
 
+/// Emitted when a taker fills a chain of listings in one instruction
+/// (each hop also emits SwapExecuted)
+#[event]
+pub struct RouteExecuted {
+    pub taker: Pubkey,
+    pub token_mint_in: Pubkey,
+    pub token_mint_out: Pubkey,
+    /// Tokens paid into the first hop
+    pub amount_in: u64,
+    /// Tokens received from the last hop (after transfer fees)
+    pub amount_out: u64,
+    /// Tokens paid into each hop that the hop did not spend, in route order.
+    /// Later entries are intermediate tokens left in the taker's accounts
+    pub amounts_unspent: Vec<u64>,
+    /// Listings filled, in route order
+    pub listings: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when a sweep fills listings of one pair best-price-first
+/// (each fill also emits SwapExecuted)
+#[event]
+pub struct SweepExecuted {
+    pub taker: Pubkey,
+    pub token_mint_source: Pubkey,
+    pub token_mint_destination: Pubkey,
+    /// Destination tokens paid, fees included
+    pub amount_in: u64,
+    /// Source tokens received (after transfer fees)
+    pub amount_out: u64,
+    pub fee_amount: u64,
+    /// Listings filled, in fill order
+    pub listings: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted for every listing a sweep passes over without filling
+#[event]
+pub struct SweepListingSkipped {
+    pub taker: Pubkey,
+    pub listing: Pubkey,
+    pub reason: SweepSkipReason,
+    pub timestamp: i64,
+}
+
+/// Emitted when two crossing listings are settled against each other
+#[event]
+pub struct ListingsMatched {
+    pub listing_a_id: u64,
+    pub maker_a: Pubkey,
+    pub listing_b_id: u64,
+    pub maker_b: Pubkey,
+    pub matched_by: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    /// Token A sold by listing A
+    pub amount_a: u64,
+    /// Token B sold by listing B
+    pub amount_b: u64,
+    /// Token B owed to maker A at listing A's price (before fee)
+    pub amount_b_to_maker_a: u64,
+    /// Token B left between the two prices, paid to the platform
+    pub surplus: u64,
+    /// Fee paid by maker A in token B
+    pub fee_a: u64,
+    /// Fee paid by maker B in token A
+    pub fee_b: u64,
+    pub new_status_a: ListingStatus,
+    pub new_status_b: ListingStatus,
+    pub timestamp: i64,
+}
+
+/// Emitted when a taker escrows a counter-offer against a listing
+#[event]
+pub struct OfferMade {
+    pub offer: Pubkey,
+    pub offer_id: u64,
+    pub listing_id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub amount_source: u64,
+    pub amount_destination: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a maker accepts a counter-offer
+#[event]
+pub struct OfferAccepted {
+    pub offer: Pubkey,
+    pub offer_id: u64,
+    pub listing_id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub amount_source: u64,
+    pub amount_destination: u64,
+    pub fee_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a taker withdraws a counter-offer
+#[event]
+pub struct OfferWithdrawn {
+    pub offer: Pubkey,
+    pub offer_id: u64,
+    pub listing_id: u64,
+    pub taker: Pubkey,
+    pub amount_returned: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an expired counter-offer is closed
+#[event]
+pub struct OfferExpired {
+    pub offer: Pubkey,
+    pub offer_id: u64,
+    pub listing_id: u64,
+    pub taker: Pubkey,
+    pub closer: Pubkey,
+    pub amount_returned: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a signed maker order is settled
+#[event]
+pub struct SignedOrderFilled {
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub nonce: u64,
+    pub token_mint_source: Pubkey,
+    pub token_mint_destination: Pubkey,
+    pub side: ListingSide,
+    pub amount_source: u64,
+    pub amount_destination: u64,
+    /// Quote token amount of the fill (destination for asks, source for bids)
+    pub quote_volume: u64,
+    pub fee_amount: u64,
+    /// Token-2022 transfer fee withheld from the source tokens sent to the taker
+    pub source_transfer_fee: u64,
+    /// Token-2022 transfer fees withheld from the destination legs
+    pub destination_transfer_fee: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a signed RFQ quote is settled
+#[event]
+pub struct RfqFilled {
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub nonce: u64,
+    pub token_mint_source: Pubkey,
+    pub token_mint_destination: Pubkey,
+    pub side: ListingSide,
+    pub amount_source: u64,
+    pub amount_destination: u64,
+    /// Quote token amount of the fill (destination for asks, source for bids)
+    pub quote_volume: u64,
+    pub fee_amount: u64,
+    /// Token-2022 transfer fee withheld from the source tokens sent to the taker
+    pub source_transfer_fee: u64,
+    /// Token-2022 transfer fees withheld from the destination legs
+    pub destination_transfer_fee: u64,
+    pub expires_at_slot: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a maker cancels a single signed order nonce
+#[event]
+pub struct SignedOrderCancelled {
+    pub maker: Pubkey,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a maker cancels every signed order below a nonce
+#[event]
+pub struct SignedOrderNonceFloorRaised {
+    pub maker: Pubkey,
+    pub nonce_floor: u64,
+    pub timestamp: i64,
+}
+
 /// Emitted when a user profile is created
 #[event]
 pub struct UserProfileCreated {
@@ -120,6 +390,15 @@ pub struct UserPreferencesUpdated {
     pub timestamp: i64,
 }
 
+/// Emitted when a maker invalidates all of their open listings
+#[event]
+pub struct CancelEpochBumped {
+    pub user: Pubkey,
+    pub cancel_epoch: u64,
+    pub active_listings: u16,
+    pub timestamp: i64,
+}
+
 /// Emitted when token whitelist is updated
 #[event]
 pub struct TokenWhitelistUpdated {
@@ -57,6 +57,10 @@ pub fn handler(ctx: Context<InitializePlatform>, params: InitializePlatformParam
     platform.max_listings_per_user = params.max_listings_per_user;
     platform.is_paused = false;
     platform.whitelist_enabled = false;
+    platform.gtc_enabled = false;
+    platform.max_gtc_listings_per_user = DEFAULT_MAX_GTC_LISTINGS_PER_USER;
+    platform.rejected_mint_features = DEFAULT_REJECTED_MINT_FEATURES;
+    platform.flagged_mint_features = DEFAULT_FLAGGED_MINT_FEATURES;
     platform.total_listings_created = 0;
     platform.total_swaps_executed = 0;
     platform.total_volume_traded = 0;
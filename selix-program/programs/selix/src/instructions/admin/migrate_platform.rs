@@ -0,0 +1,68 @@
+use crate::{constants::*, events::AccountMigrated, state::Platform, utils::*};
+use anchor_lang::prelude::*;
+
+/// Size of a platform account written before the mint feature policy
+/// (the last two bytes of the layout) was appended
+const PRE_MINT_POLICY_SIZE: usize = 8 + Platform::INIT_SPACE - 2;
+
+#[derive(Accounts)]
+pub struct MigratePlatform<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: May still use an older, shorter layout; owner, discriminator
+    /// and authority are validated in the handler
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub platform: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigratePlatform>) -> Result<()> {
+    let platform = ctx.accounts.platform.to_account_info();
+    let authority = ctx.accounts.authority.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    validate_legacy_account(&platform, Platform::DISCRIMINATOR, &authority)?;
+
+    let old_size = platform.data_len();
+    let new_size = 8 + Platform::INIT_SPACE;
+    grow_pda_account(
+        &ctx.accounts.authority.to_account_info(),
+        &platform,
+        new_size,
+        &ctx.accounts.system_program,
+    )?;
+
+    // Platforms that predate the mint policy start from the default policy,
+    // not from the permissive zeroed tail
+    if old_size <= PRE_MINT_POLICY_SIZE {
+        let mut data = platform.try_borrow_mut_data()?;
+        let mut state = Platform::try_deserialize(&mut &data[..])?;
+        state.rejected_mint_features = DEFAULT_REJECTED_MINT_FEATURES;
+        state.flagged_mint_features = DEFAULT_FLAGGED_MINT_FEATURES;
+        state.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(AccountMigrated {
+        account: platform.key(),
+        owner: authority,
+        old_size: old_size as u64,
+        new_size: platform.data_len() as u64,
+        timestamp: current_time,
+    });
+
+    // Admin audit log
+    msg!("ADMIN ACTION: PLATFORM MIGRATED");
+    msg!("-------------------------------------");
+    msg!("Authority: {}", authority);
+    msg!("Old Size: {}", old_size);
+    msg!("New Size: {}", platform.data_len());
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
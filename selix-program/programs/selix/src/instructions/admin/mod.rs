@@ -2,12 +2,14 @@
 
 pub mod initialize_platform;
 pub mod manage_whitelist;
+pub mod migrate_platform;
 pub mod pause_platform;
 pub mod set_fee_collector;
 pub mod update_config;
 
 pub use initialize_platform::*;
 pub use manage_whitelist::*;
+pub use migrate_platform::*;
 pub use pause_platform::*;
 pub use set_fee_collector::*;
 pub use update_config::*;
@@ -3,7 +3,7 @@ use crate::{
     errors::SelixError,
     events::PlatformConfigUpdated,
     state::Platform,
-    utils::{validate_duration_bounds, validate_fee_bps},
+    utils::{validate_duration_bounds, validate_fee_bps, validate_mint_feature_mask},
 };
 use anchor_lang::prelude::*;
 
@@ -15,6 +15,10 @@ pub struct UpdateConfigParams {
     pub min_trade_amount: Option<u64>,
     pub max_listings_per_user: Option<u16>,
     pub whitelist_enabled: Option<bool>,
+    pub gtc_enabled: Option<bool>,
+    pub max_gtc_listings_per_user: Option<u16>,
+    pub rejected_mint_features: Option<u8>,
+    pub flagged_mint_features: Option<u8>,
 }
 
 #[derive(Accounts)]
@@ -75,6 +79,28 @@ pub fn handler(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result
         platform.whitelist_enabled = whitelist;
     }
 
+    // Update good-till-cancelled settings if provided
+    if let Some(gtc_enabled) = params.gtc_enabled {
+        platform.gtc_enabled = gtc_enabled;
+    }
+    if let Some(max_gtc_listings) = params.max_gtc_listings_per_user {
+        require!(
+            max_gtc_listings <= platform.max_listings_per_user,
+            SelixError::InvalidAmount
+        );
+        platform.max_gtc_listings_per_user = max_gtc_listings;
+    }
+
+    // Update mint feature policy if provided
+    if let Some(rejected) = params.rejected_mint_features {
+        validate_mint_feature_mask(rejected)?;
+        platform.rejected_mint_features = rejected;
+    }
+    if let Some(flagged) = params.flagged_mint_features {
+        validate_mint_feature_mask(flagged)?;
+        platform.flagged_mint_features = flagged;
+    }
+
     platform.updated_at = current_time;
 
     emit!(PlatformConfigUpdated {
@@ -107,6 +133,27 @@ pub fn handler(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result
     if params.whitelist_enabled.is_some() {
         msg!("Whitelist Enabled: {}", platform.whitelist_enabled);
     }
+    if params.gtc_enabled.is_some() {
+        msg!("GTC Enabled: {}", platform.gtc_enabled);
+    }
+    if params.max_gtc_listings_per_user.is_some() {
+        msg!(
+            "New Max GTC Listings: {}",
+            platform.max_gtc_listings_per_user
+        );
+    }
+    if params.rejected_mint_features.is_some() {
+        msg!(
+            "Rejected Mint Features: {:#07b}",
+            platform.rejected_mint_features
+        );
+    }
+    if params.flagged_mint_features.is_some() {
+        msg!(
+            "Flagged Mint Features: {:#07b}",
+            platform.flagged_mint_features
+        );
+    }
     msg!("Timestamp: {}", current_time);
 
     Ok(())
@@ -0,0 +1,76 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::ListingActivated,
+    state::{Listing, ListingStatus, Platform},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ActivateListing<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, listing.maker.as_ref(), &listing.id.to_le_bytes()],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+}
+
+pub fn handler(ctx: Context<ActivateListing>) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate platform not paused
+    validate_not_paused(&ctx.accounts.platform)?;
+
+    // Validate listing is scheduled
+    require!(
+        listing.status == ListingStatus::Pending,
+        SelixError::InvalidListingStatus
+    );
+
+    // Triggered listings activate through trigger_listing
+    require!(!listing.has_trigger(), SelixError::TriggerNotMet);
+
+    // Validate start time has passed
+    require!(
+        listing.is_ready_to_activate(current_time, None),
+        SelixError::ListingNotStarted
+    );
+
+    // Validate not expired
+    require!(
+        !listing.is_expired(current_time),
+        SelixError::ListingExpired
+    );
+
+    listing.status = ListingStatus::Active;
+    listing.updated_at = current_time;
+
+    emit!(ListingActivated {
+        listing_id: listing.id,
+        maker: listing.maker,
+        activated_by: ctx.accounts.caller.key(),
+        timestamp: current_time,
+    });
+
+    // Listing audit log
+    msg!("LISTING ACTIVATED");
+    msg!("--------------------");
+    msg!("Listing ID: {}", listing.id);
+    msg!("Maker: {}", listing.maker);
+    msg!("Activated By: {}", ctx.accounts.caller.key());
+    msg!("Starts At: {}", listing.starts_at);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
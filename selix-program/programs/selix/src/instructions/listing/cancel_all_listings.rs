@@ -0,0 +1,135 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::ListingCancelled,
+    instructions::listing::cancel_listing::return_listing_escrow,
+    state::{Listing, UserProfile},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Accounts passed in `remaining_accounts` for every listing, in order:
+/// listing, vault, maker token account in the source mint (any account for
+/// native SOL), source mint and its token program
+pub const BATCH_CANCEL_ACCOUNTS: usize = 5;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CancelAllListingsParams {
+    /// Number of listings in `remaining_accounts`
+    pub listings: u8,
+}
+
+#[derive(Accounts)]
+pub struct CancelAllListings<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Option<Account<'info, UserProfile>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelAllListings<'info>>,
+    params: CancelAllListingsParams,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate batch shape
+    let listing_count = params.listings as usize;
+    require!(
+        (1..=MAX_BATCH_LISTINGS).contains(&listing_count),
+        SelixError::InvalidListingBatch
+    );
+    let listing_accounts_len = listing_count * BATCH_CANCEL_ACCOUNTS;
+    require!(
+        ctx.remaining_accounts.len() >= listing_accounts_len,
+        SelixError::InvalidListingBatch
+    );
+    // Accounts after the listings are extra accounts for transfer-hook mints
+    let (listing_accounts, hook_accounts) = ctx.remaining_accounts.split_at(listing_accounts_len);
+
+    let maker_key = ctx.accounts.maker.key();
+    let maker = ctx.accounts.maker.to_account_info();
+
+    for accounts in listing_accounts.chunks(BATCH_CANCEL_ACCOUNTS) {
+        let listing: Account<'info, Listing> = Account::try_from(&accounts[0])?;
+        let vault: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[1])?;
+        let token_mint_source: InterfaceAccount<'info, Mint> =
+            InterfaceAccount::try_from(&accounts[3])?;
+        let token_program: Interface<'info, TokenInterface> = Interface::try_from(&accounts[4])?;
+
+        // Same checks as cancel_listing
+        require_keys_eq!(listing.maker, maker_key, SelixError::UnauthorizedAuthority);
+        require!(
+            listing.status.can_be_cancelled(),
+            SelixError::InvalidListingStatus
+        );
+        require_keys_eq!(
+            token_mint_source.key(),
+            listing.token_mint_source,
+            SelixError::TokenAccountMintMismatch
+        );
+        require_keys_eq!(
+            *token_mint_source.to_account_info().owner,
+            token_program.key(),
+            SelixError::InvalidTokenAccount
+        );
+        validate_token_account(&vault, &listing.token_mint_source, &listing.key())?;
+
+        // A wSOL vault unwraps straight to the maker when closed
+        let maker_token_account_source = if is_native_mint(&listing.token_mint_source) {
+            None
+        } else {
+            let account: InterfaceAccount<'info, TokenAccount> =
+                InterfaceAccount::try_from(&accounts[2])?;
+            validate_token_account(&account, &listing.token_mint_source, &maker_key)?;
+            Some(account)
+        };
+
+        let amount_to_return = return_listing_escrow(
+            &listing,
+            &vault,
+            &maker,
+            maker_token_account_source.as_ref(),
+            &token_mint_source,
+            &token_program,
+            hook_accounts,
+        )?;
+
+        // Update user profile if exists
+        if let Some(profile) = &mut ctx.accounts.maker_profile {
+            profile.record_listing_cancelled(listing.good_till_cancelled, current_time)?;
+        }
+
+        emit!(ListingCancelled {
+            listing_id: listing.id,
+            maker: maker_key,
+            amount_returned: amount_to_return,
+            timestamp: current_time,
+        });
+
+        msg!(
+            "LISTING CANCELLED: id={} status={:?} returned={}",
+            listing.id,
+            listing.status,
+            amount_to_return
+        );
+
+        // Close the listing account, returning its rent to the maker
+        listing.close(maker.clone())?;
+    }
+
+    msg!("ALL LISTINGS CANCELLED");
+    msg!("--------------------");
+    msg!("Maker: {}", maker_key);
+    msg!("Listings: {}", listing_count);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::{
     constants::*,
     errors::SelixError,
     events::ListingCancelled,
-    state::{Listing, ListingStatus, UserProfile},
+    state::{Listing, UserProfile},
     utils::*,
 };
 use anchor_lang::prelude::*;
@@ -37,71 +37,99 @@ pub struct CancelListing<'info> {
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
 
+    /// Optional: omitted when the source is native SOL
     #[account(
         mut,
         associated_token::mint = token_mint_source,
         associated_token::authority = maker,
         associated_token::token_program = token_program,
     )]
-    pub maker_token_account_source: InterfaceAccount<'info, TokenAccount>,
+    pub maker_token_account_source: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(mint::token_program = token_program)]
+    /// Writable so withheld transfer fees can be harvested before the vault closes
+    #[account(mut, mint::token_program = token_program)]
     pub token_mint_source: InterfaceAccount<'info, Mint>,
 
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handler(ctx: Context<CancelListing>) -> Result<()> {
-    let listing = &ctx.accounts.listing;
-    let current_time = Clock::get()?.unix_timestamp;
-
-    // Validate listing can be cancelled (Active or PartiallyFilled)
-    require!(
-        listing.status == ListingStatus::Active || listing.status == ListingStatus::PartiallyFilled,
-        SelixError::InvalidListingStatus
-    );
-
-    let amount_to_return = ctx.accounts.vault.amount;
+/// Return a cancelled listing's escrow to the maker and close its vault.
+/// Shared with `cancel_all_listings`; returns the amount returned.
+pub fn return_listing_escrow<'info>(
+    listing: &Account<'info, Listing>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    maker: &AccountInfo<'info>,
+    maker_token_account_source: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_mint_source: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<u64> {
+    let amount_to_return = vault.amount;
 
     // Return remaining tokens from vault to maker
     if amount_to_return > 0 {
-        let maker_key = ctx.accounts.maker.key();
         let listing_seeds = &[
             LISTING_SEED,
-            maker_key.as_ref(),
+            listing.maker.as_ref(),
             &listing.id.to_le_bytes(),
             &[listing.bump],
         ];
         let signer_seeds = &[&listing_seeds[..]];
 
-        transfer_tokens(
-            &ctx.accounts.vault,
-            &ctx.accounts.maker_token_account_source,
-            &ctx.accounts.token_mint_source,
-            &ctx.accounts.listing.to_account_info(),
-            &ctx.accounts.token_program,
-            amount_to_return,
-            Some(signer_seeds),
-        )?;
+        // A wSOL vault unwraps straight to the maker when closed
+        if !is_native_mint(&token_mint_source.key()) {
+            let maker_token_account_source =
+                maker_token_account_source.ok_or(SelixError::InvalidTokenAccount)?;
+
+            transfer_tokens(
+                vault,
+                maker_token_account_source,
+                token_mint_source,
+                &listing.to_account_info(),
+                token_program,
+                amount_to_return,
+                Some(signer_seeds),
+                remaining_accounts,
+            )?;
+        }
 
         // Close vault
+        harvest_withheld_fees(vault, token_mint_source, token_program)?;
         close_token_account(
-            &ctx.accounts.vault,
-            &ctx.accounts.maker.to_account_info(),
-            &ctx.accounts.listing.to_account_info(),
-            &ctx.accounts.token_program,
+            vault,
+            maker,
+            &listing.to_account_info(),
+            token_program,
             Some(signer_seeds),
         )?;
     }
 
+    Ok(amount_to_return)
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CancelListing<'info>>) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate listing can be cancelled (Pending, Active or PartiallyFilled)
+    require!(
+        listing.status.can_be_cancelled(),
+        SelixError::InvalidListingStatus
+    );
+
+    let amount_to_return = return_listing_escrow(
+        listing,
+        &ctx.accounts.vault,
+        &ctx.accounts.maker.to_account_info(),
+        ctx.accounts.maker_token_account_source.as_ref(),
+        &ctx.accounts.token_mint_source,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+    )?;
+
     // Update user profile if exists
     if let Some(profile) = &mut ctx.accounts.maker_profile {
-        profile.listings_cancelled = profile
-            .listings_cancelled
-            .checked_add(1)
-            .ok_or(SelixError::ArithmeticOverflow)?;
-        profile.active_listings = profile.active_listings.saturating_sub(1);
-        profile.last_activity_at = current_time;
+        profile.record_listing_cancelled(listing.good_till_cancelled, current_time)?;
     }
 
     emit!(ListingCancelled {
@@ -2,7 +2,7 @@ use crate::{
     constants::*,
     errors::SelixError,
     events::ListingExpired,
-    state::{Listing, Platform},
+    state::{Listing, Platform, UserProfile},
     utils::*,
 };
 use anchor_lang::prelude::*;
@@ -17,6 +17,14 @@ pub struct CloseExpiredListing<'info> {
     #[account(mut)]
     pub maker: UncheckedAccount<'info>,
 
+    /// Optional: required to close listings invalidated by the maker's cancel epoch
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Option<Account<'info, UserProfile>>,
+
     #[account(
         seeds = [PLATFORM_SEED, platform.authority.as_ref()],
         bump = platform.bump,
@@ -40,28 +48,36 @@ pub struct CloseExpiredListing<'info> {
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
 
+    /// Optional: omitted when the source is native SOL
     #[account(
         mut,
         associated_token::mint = token_mint_source,
         associated_token::authority = maker,
         associated_token::token_program = token_program,
     )]
-    pub maker_token_account_source: InterfaceAccount<'info, TokenAccount>,
+    pub maker_token_account_source: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(mint::token_program = token_program)]
+    /// Writable so withheld transfer fees can be harvested before the vault closes
+    #[account(mut, mint::token_program = token_program)]
     pub token_mint_source: InterfaceAccount<'info, Mint>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CloseExpiredListing>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseExpiredListing<'info>>) -> Result<()> {
     let listing = &ctx.accounts.listing;
     let current_time = Clock::get()?.unix_timestamp;
 
-    // Validate listing is expired
+    // Validate listing is expired (good-till-cancelled listings never are)
+    // or was invalidated by the maker's cancel epoch
+    let cancelled_by_epoch = ctx
+        .accounts
+        .maker_profile
+        .as_ref()
+        .is_some_and(|profile| listing.is_cancelled_by_epoch(profile.cancel_epoch));
     require!(
-        is_expired(listing.expires_at)?,
+        listing.is_expired(current_time) || cancelled_by_epoch,
         SelixError::ListingNotExpired
     );
 
@@ -78,17 +94,32 @@ pub fn handler(ctx: Context<CloseExpiredListing>) -> Result<()> {
         ];
         let signer_seeds = &[&listing_seeds[..]];
 
-        transfer_tokens(
+        // A wSOL vault unwraps straight to the maker when closed
+        if !is_native_mint(&ctx.accounts.token_mint_source.key()) {
+            let maker_token_account_source = ctx
+                .accounts
+                .maker_token_account_source
+                .as_ref()
+                .ok_or(SelixError::InvalidTokenAccount)?;
+
+            transfer_tokens(
+                &ctx.accounts.vault,
+                maker_token_account_source,
+                &ctx.accounts.token_mint_source,
+                &ctx.accounts.listing.to_account_info(),
+                &ctx.accounts.token_program,
+                amount_to_return,
+                Some(signer_seeds),
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        // Close vault
+        harvest_withheld_fees(
             &ctx.accounts.vault,
-            &ctx.accounts.maker_token_account_source,
             &ctx.accounts.token_mint_source,
-            &ctx.accounts.listing.to_account_info(),
             &ctx.accounts.token_program,
-            amount_to_return,
-            Some(signer_seeds),
         )?;
-
-        // Close vault
         close_token_account(
             &ctx.accounts.vault,
             &ctx.accounts.maker.to_account_info(),
@@ -101,6 +132,11 @@ pub fn handler(ctx: Context<CloseExpiredListing>) -> Result<()> {
     // Optional: Pay small reward to closer from listing account rent
     // The rent is returned to maker via close = maker constraint
 
+    // Update user profile if provided
+    if let Some(profile) = &mut ctx.accounts.maker_profile {
+        profile.record_listing_closed(listing.good_till_cancelled);
+    }
+
     emit!(ListingExpired {
         listing_id: listing.id,
         maker: ctx.accounts.maker.key(),
@@ -116,6 +152,7 @@ pub fn handler(ctx: Context<CloseExpiredListing>) -> Result<()> {
     msg!("Maker: {}", ctx.accounts.maker.key());
     msg!("Closer: {}", ctx.accounts.closer.key());
     msg!("Expired At: {}", listing.expires_at);
+    msg!("Cancelled By Epoch: {}", cancelled_by_epoch);
     msg!("Amount Returned: {}", amount_to_return);
     msg!("Source Token: {}", ctx.accounts.token_mint_source.key());
     msg!("Vault Closed: {}", ctx.accounts.vault.key());
@@ -1,8 +1,11 @@
 use crate::{
     constants::*,
     errors::SelixError,
-    events::ListingCreated,
-    state::{Listing, ListingStatus, Platform, TokenWhitelist, UserProfile},
+    events::{ListingCreated, ListingMintFlagged},
+    state::{
+        Listing, ListingKind, ListingSide, ListingStatus, Platform, TokenWhitelist,
+        TriggerCondition, UserProfile,
+    },
     utils::*,
 };
 use anchor_lang::prelude::*;
@@ -11,14 +14,66 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DutchAuctionParams {
+    /// Destination amount for the full listing at expiry
+    pub floor_amount_destination: u64,
+    /// Price step interval in seconds (0 = linear decay)
+    pub step_seconds: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReleaseScheduleParams {
+    /// Seconds after the start before anything unlocks
+    pub cliff_seconds: i64,
+    /// Seconds after the start until everything is unlocked
+    pub release_seconds: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TriggerParams {
+    /// Direction the oracle price must cross
+    pub condition: TriggerCondition,
+    /// Trigger price in the price feed's units and exponent
+    pub price: i64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct CreateListingParams {
     pub id: u64,
+    /// Ask sells the source (base) token, Bid escrows the source (quote) token
+    /// to buy the destination (base) token
+    pub side: ListingSide,
     pub amount_source: u64,
     pub amount_destination: u64,
     pub min_fill_amount: u64,
     pub max_slippage_bps: u16,
+    /// Ignored for good-till-cancelled listings
     pub duration_seconds: i64,
+    /// No expiry; requires platform.gtc_enabled
+    pub good_till_cancelled: bool,
+    /// Reject partial fills; cannot be changed after creation
+    pub all_or_none: bool,
+    /// Iceberg: only this much is visible and fillable at once
+    pub display_amount: Option<u64>,
+    /// Restrict fills to these takers (empty for a public listing)
+    pub allowed_takers: Vec<Pubkey>,
+    /// Decay the price from `amount_destination` down to a floor by expiry
+    pub dutch_auction: Option<DutchAuctionParams>,
+    /// Price fills from the `price_feed` account with this premium (positive)
+    /// or discount (negative) in basis points; `amount_destination` is then
+    /// only a reference amount
+    pub oracle_premium_bps: Option<i16>,
+    /// Escrow now but keep the listing pending until this time
+    pub starts_at: Option<i64>,
+    /// Unlock the listed inventory gradually from the start time
+    pub release_schedule: Option<ReleaseScheduleParams>,
+    /// Keep the listing pending until the `price_feed` price crosses this
+    /// trigger (stop-loss); fills then use the listing's own pricing
+    pub trigger: Option<TriggerParams>,
+    /// `token_mint_destination` is a collection mint and any verified NFT
+    /// of that collection is accepted
+    pub destination_collection: bool,
 }
 
 #[derive(Accounts)]
@@ -55,22 +110,23 @@ pub struct CreateListing<'info> {
         payer = maker,
         associated_token::mint = token_mint_source,
         associated_token::authority = listing,
-        associated_token::token_program = token_program,
+        associated_token::token_program = token_program_source,
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
 
+    /// Optional: omitted when the source is native SOL
     #[account(
         mut,
         associated_token::mint = token_mint_source,
         associated_token::authority = maker,
-        associated_token::token_program = token_program,
+        associated_token::token_program = token_program_source,
     )]
-    pub maker_token_account_source: InterfaceAccount<'info, TokenAccount>,
+    pub maker_token_account_source: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(mint::token_program = token_program)]
+    #[account(mint::token_program = token_program_source)]
     pub token_mint_source: InterfaceAccount<'info, Mint>,
 
-    #[account(mint::token_program = token_program)]
+    #[account(mint::token_program = token_program_destination)]
     pub token_mint_destination: InterfaceAccount<'info, Mint>,
 
     /// Optional: required when platform.whitelist_enabled is true
@@ -79,159 +135,529 @@ pub struct CreateListing<'info> {
     /// Optional: required when platform.whitelist_enabled is true
     pub dest_whitelist: Option<Account<'info, TokenWhitelist>>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: Optional: required for oracle-pegged and triggered listings.
+    /// Validated as a Pyth price update account in the handler.
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    pub token_program_source: Interface<'info, TokenInterface>,
+    pub token_program_destination: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateListing>, params: CreateListingParams) -> Result<()> {
-    let platform = &ctx.accounts.platform;
-    let current_time = Clock::get()?.unix_timestamp;
+/// Accounts a new listing is validated against, shared with `create_listings_batch`
+pub struct ListingContext<'a, 'info> {
+    pub maker: Pubkey,
+    pub maker_profile: &'a UserProfile,
+    pub platform: &'a Platform,
+    pub token_mint_source: &'a InterfaceAccount<'info, Mint>,
+    pub token_mint_destination: &'a InterfaceAccount<'info, Mint>,
+    pub source_whitelist: Option<&'a Account<'info, TokenWhitelist>>,
+    pub dest_whitelist: Option<&'a Account<'info, TokenWhitelist>>,
+    pub price_feed: Option<&'a UncheckedAccount<'info>>,
+}
+
+/// A validated listing that is ready to be escrowed
+pub struct NewListing {
+    pub listing: Listing,
+    /// Token-2022 transfer fee withheld on the deposit into the vault
+    pub source_transfer_fee: u64,
+    pub source_mint_features: u8,
+    pub destination_mint_features: u8,
+}
+
+/// Validate `params` against the platform, the maker's limits and both mints,
+/// and build the listing state
+pub fn build_listing(
+    ctx: &ListingContext,
+    params: &CreateListingParams,
+    bump: u8,
+    current_time: i64,
+    program_id: &Pubkey,
+) -> Result<NewListing> {
+    let platform = ctx.platform;
 
     // Validate platform not paused
     validate_not_paused(platform)?;
 
-    // Validate amounts
-    validate_amount(params.amount_source, platform.min_trade_amount)?;
-    validate_amount(params.amount_destination, platform.min_trade_amount)?;
-    validate_min_fill_amount(params.min_fill_amount, params.amount_source)?;
+    // NFTs trade one token at a time and cannot be split
+    let source_is_nft = is_nft(ctx.token_mint_source.decimals, ctx.token_mint_source.supply);
+    let destination_is_nft = is_nft(
+        ctx.token_mint_destination.decimals,
+        ctx.token_mint_destination.supply,
+    );
+    require!(
+        !params.destination_collection || destination_is_nft,
+        SelixError::NotAnNft
+    );
+    let all_or_none = params.all_or_none || source_is_nft || destination_is_nft;
+
+    // Token-2022 transfer fees are withheld on the way into the vault,
+    // so the listing only offers what the vault actually receives
+    let source_transfer_fee = calculate_transfer_fee(ctx.token_mint_source, params.amount_source)?;
+    let amount_source = params
+        .amount_source
+        .checked_sub(source_transfer_fee)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+
+    // Validate amounts (NFTs skip the dust check)
+    validate_trade_amount(amount_source, source_is_nft, platform.min_trade_amount)?;
+    validate_trade_amount(
+        params.amount_destination,
+        destination_is_nft,
+        platform.min_trade_amount,
+    )?;
+    validate_min_fill_amount(params.min_fill_amount, amount_source)?;
 
     // Validate token mints are different
     validate_different_mints(
-        &ctx.accounts.token_mint_source.key(),
-        &ctx.accounts.token_mint_destination.key(),
+        &ctx.token_mint_source.key(),
+        &ctx.token_mint_destination.key(),
     )?;
 
-    // Validate duration
-    validate_duration(params.duration_seconds, platform)?;
+    // Validate duration, or good-till-cancelled limits
+    let listing_duration = if params.good_till_cancelled {
+        require!(platform.gtc_enabled, SelixError::GoodTillCancelledDisabled);
+        validate_listing_limit(
+            ctx.maker_profile.active_gtc_listings,
+            platform.max_gtc_listings_per_user,
+        )?;
+        i64::MAX
+    } else {
+        validate_duration(params.duration_seconds, platform)?;
+        params.duration_seconds
+    };
+
+    // Validate scheduled start
+    if let Some(starts_at) = params.starts_at {
+        validate_start_time(starts_at, current_time, platform)?;
+    }
+
+    // Validate release schedule
+    if let Some(schedule) = &params.release_schedule {
+        validate_release_schedule(
+            schedule.cliff_seconds,
+            schedule.release_seconds,
+            listing_duration,
+        )?;
+    }
 
     // Validate slippage
     validate_slippage_bps(params.max_slippage_bps)?;
 
+    // Validate iceberg clip size (all-or-none and NFT listings are filled whole)
+    let display_amount = params.display_amount.unwrap_or(0);
+    if params.display_amount.is_some() {
+        validate_display_amount(
+            display_amount,
+            amount_source,
+            params.min_fill_amount,
+            all_or_none,
+        )?;
+    }
+
+    // Validate private listing taker set
+    validate_allowed_takers(&params.allowed_takers, &ctx.maker)?;
+
+    // Validate Dutch auction curve
+    require!(
+        params.dutch_auction.is_none() || params.oracle_premium_bps.is_none(),
+        SelixError::UnsupportedListingKind
+    );
+    let (mut kind, auction_floor_amount_destination, auction_step_seconds) =
+        match &params.dutch_auction {
+            Some(auction) => {
+                // The price curve needs an end time
+                require!(
+                    !params.good_till_cancelled,
+                    SelixError::InvalidAuctionParameters
+                );
+                validate_dutch_auction(
+                    params.amount_destination,
+                    auction.floor_amount_destination,
+                    auction.step_seconds,
+                    params.duration_seconds,
+                )?;
+                (
+                    ListingKind::DutchAuction,
+                    auction.floor_amount_destination,
+                    auction.step_seconds,
+                )
+            }
+            None => (ListingKind::FixedPrice, 0, 0),
+        };
+
+    // Validate the price feed used by the oracle peg and the trigger
+    let (price_feed, price_feed_id) =
+        if params.oracle_premium_bps.is_some() || params.trigger.is_some() {
+            let feed = ctx.price_feed.ok_or(SelixError::InvalidPriceFeed)?;
+            let price = load_oracle_price(feed, None, current_time)?;
+            (feed.key(), price.feed_id)
+        } else {
+            (Pubkey::default(), [0u8; 32])
+        };
+
+    // Validate oracle peg (an NFT cannot be priced from a feed)
+    let oracle_premium_bps = match params.oracle_premium_bps {
+        Some(premium_bps) => {
+            require!(!destination_is_nft, SelixError::UnsupportedListingKind);
+            validate_oracle_premium(premium_bps)?;
+            kind = ListingKind::OraclePegged;
+            premium_bps
+        }
+        None => 0,
+    };
+
+    // Validate stop-loss trigger (the auction curve would run before the trigger)
+    let (trigger_condition, trigger_price) = match &params.trigger {
+        Some(trigger) => {
+            require!(
+                trigger.condition != TriggerCondition::None && trigger.price > 0,
+                SelixError::InvalidTrigger
+            );
+            require!(
+                kind != ListingKind::DutchAuction,
+                SelixError::UnsupportedListingKind
+            );
+            (trigger.condition, trigger.price)
+        }
+        None => (TriggerCondition::None, 0),
+    };
+
     // Validate whitelist if enabled
     if platform.whitelist_enabled {
-        // Source token whitelist check
-        let source_wl = ctx
-            .accounts
-            .source_whitelist
-            .as_ref()
-            .ok_or(SelixError::TokenNotWhitelisted)?;
-        let (expected_source_pda, _) = Pubkey::find_program_address(
-            &[
-                WHITELIST_SEED,
-                ctx.accounts.token_mint_source.key().as_ref(),
-            ],
-            ctx.program_id,
-        );
-        require_keys_eq!(source_wl.key(), expected_source_pda, SelixError::InvalidPDA);
-        require!(source_wl.is_whitelisted, SelixError::TokenNotWhitelisted);
-
-        // Destination token whitelist check
-        let dest_wl = ctx
-            .accounts
-            .dest_whitelist
-            .as_ref()
-            .ok_or(SelixError::TokenNotWhitelisted)?;
-        let (expected_dest_pda, _) = Pubkey::find_program_address(
-            &[
-                WHITELIST_SEED,
-                ctx.accounts.token_mint_destination.key().as_ref(),
-            ],
-            ctx.program_id,
-        );
-        require_keys_eq!(dest_wl.key(), expected_dest_pda, SelixError::InvalidPDA);
-        require!(dest_wl.is_whitelisted, SelixError::TokenNotWhitelisted);
+        validate_whitelisted(
+            ctx.source_whitelist,
+            &ctx.token_mint_source.key(),
+            program_id,
+        )?;
+        validate_whitelisted(
+            ctx.dest_whitelist,
+            &ctx.token_mint_destination.key(),
+            program_id,
+        )?;
     }
 
+    // Validate both mints against the platform's mint feature policy
+    let source_mint_features = mint_features(ctx.token_mint_source)?;
+    let destination_mint_features = mint_features(ctx.token_mint_destination)?;
+    validate_mint_features(source_mint_features, platform.rejected_mint_features)?;
+    validate_mint_features(destination_mint_features, platform.rejected_mint_features)?;
+
     // Check user listing limit
     validate_listing_limit(
-        ctx.accounts.maker_profile.active_listings,
+        ctx.maker_profile.active_listings,
         platform.max_listings_per_user,
     )?;
 
-    // Check maker has sufficient balance
-    check_sufficient_balance(
-        &ctx.accounts.maker_token_account_source,
-        params.amount_source,
+    // Calculate expiry (duration runs from the scheduled start)
+    let starts_at = params.starts_at.unwrap_or(current_time);
+    let status = if params.starts_at.is_some() || params.trigger.is_some() {
+        ListingStatus::Pending
+    } else {
+        ListingStatus::Active
+    };
+    let expires_at = if params.good_till_cancelled {
+        i64::MAX
+    } else {
+        starts_at
+            .checked_add(params.duration_seconds)
+            .ok_or(SelixError::ArithmeticOverflow)?
+    };
+
+    let (release_cliff_at, release_end_at) = match &params.release_schedule {
+        Some(schedule) => (
+            starts_at
+                .checked_add(schedule.cliff_seconds)
+                .ok_or(SelixError::ArithmeticOverflow)?,
+            starts_at
+                .checked_add(schedule.release_seconds)
+                .ok_or(SelixError::ArithmeticOverflow)?,
+        ),
+        None => (0, 0),
+    };
+
+    let listing = Listing {
+        id: params.id,
+        maker: ctx.maker,
+        token_mint_source: ctx.token_mint_source.key(),
+        token_mint_destination: ctx.token_mint_destination.key(),
+        source_is_nft,
+        destination_is_nft,
+        destination_is_collection: params.destination_collection,
+        side: params.side,
+        amount_source_total: amount_source,
+        amount_source_remaining: amount_source,
+        amount_destination_total: params.amount_destination,
+        amount_destination_remaining: params.amount_destination,
+        min_fill_amount: params.min_fill_amount,
+        max_slippage_bps: params.max_slippage_bps,
+        all_or_none,
+        display_amount,
+        amount_source_displayed: display_amount,
+        kind,
+        auction_floor_amount_destination,
+        auction_step_seconds,
+        price_feed,
+        price_feed_id,
+        oracle_premium_bps,
+        trigger_condition,
+        trigger_price,
+        allowed_takers: params.allowed_takers.clone(),
+        starts_at,
+        expires_at,
+        good_till_cancelled: params.good_till_cancelled,
+        release_cliff_at,
+        release_end_at,
+        created_at: current_time,
+        updated_at: current_time,
+        status,
+        fill_count: 0,
+        cancel_epoch: ctx.maker_profile.cancel_epoch,
+        bump,
+    };
+
+    Ok(NewListing {
+        listing,
+        source_transfer_fee,
+        source_mint_features,
+        destination_mint_features,
+    })
+}
+
+/// Move the listed tokens from the maker into the vault, wrapping native SOL
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_to_vault<'info>(
+    maker: &AccountInfo<'info>,
+    maker_token_account_source: Option<&InterfaceAccount<'info, TokenAccount>>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    token_mint_source: &InterfaceAccount<'info, Mint>,
+    token_program_source: &Interface<'info, TokenInterface>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if is_native_mint(&token_mint_source.key()) {
+        // Wrap native SOL straight into the vault
+        require!(
+            maker.lamports() >= amount,
+            SelixError::InsufficientMakerBalance
+        );
+
+        wrap_sol(maker, vault, system_program, token_program_source, amount)
+    } else {
+        let maker_token_account_source =
+            maker_token_account_source.ok_or(SelixError::InvalidTokenAccount)?;
+
+        // Check maker has sufficient balance
+        check_sufficient_balance(maker_token_account_source, amount)?;
+
+        // Transfer tokens to vault
+        transfer_tokens(
+            maker_token_account_source,
+            vault,
+            token_mint_source,
+            maker,
+            token_program_source,
+            amount,
+            None,
+            remaining_accounts,
+        )
+    }
+}
+
+/// Iceberg listings only announce the visible clip
+fn visible_amounts(listing: &Listing) -> Result<(u64, u64)> {
+    if listing.display_amount > 0 {
+        calculate_partial_amounts(
+            listing.amount_source_total,
+            listing.amount_destination_total,
+            listing.display_amount,
+        )
+    } else {
+        Ok((
+            listing.amount_source_total,
+            listing.amount_destination_total,
+        ))
+    }
+}
+
+/// Emit `ListingCreated`, plus `ListingMintFlagged` for allowed but risky
+/// mint features so indexers and UIs can flag them
+pub fn emit_listing_created(
+    new_listing: &NewListing,
+    flagged_mint_features: u8,
+    current_time: i64,
+) -> Result<()> {
+    let listing = &new_listing.listing;
+
+    let (visible_amount_source, visible_amount_destination) = visible_amounts(listing)?;
+
+    emit!(ListingCreated {
+        listing_id: listing.id,
+        maker: listing.maker,
+        token_mint_source: listing.token_mint_source,
+        token_mint_destination: listing.token_mint_destination,
+        side: listing.side,
+        source_is_nft: listing.source_is_nft,
+        destination_is_nft: listing.destination_is_nft,
+        destination_is_collection: listing.destination_is_collection,
+        amount_source: visible_amount_source,
+        amount_destination: visible_amount_destination,
+        source_transfer_fee: new_listing.source_transfer_fee,
+        is_iceberg: listing.display_amount > 0,
+        min_fill_amount: listing.min_fill_amount,
+        all_or_none: listing.all_or_none,
+        allowed_takers: listing.allowed_takers.clone(),
+        kind: listing.kind,
+        auction_floor_amount_destination: listing.auction_floor_amount_destination,
+        auction_step_seconds: listing.auction_step_seconds,
+        price_feed: listing.price_feed,
+        price_feed_id: listing.price_feed_id,
+        oracle_premium_bps: listing.oracle_premium_bps,
+        trigger_condition: listing.trigger_condition,
+        trigger_price: listing.trigger_price,
+        starts_at: listing.starts_at,
+        expires_at: listing.expires_at,
+        good_till_cancelled: listing.good_till_cancelled,
+        release_cliff_at: listing.release_cliff_at,
+        release_end_at: listing.release_end_at,
+        timestamp: current_time,
+    });
+
+    for (mint, features) in [
+        (listing.token_mint_source, new_listing.source_mint_features),
+        (
+            listing.token_mint_destination,
+            new_listing.destination_mint_features,
+        ),
+    ] {
+        let flagged = features & flagged_mint_features;
+        if flagged != 0 {
+            emit!(ListingMintFlagged {
+                listing_id: listing.id,
+                maker: listing.maker,
+                mint,
+                features: flagged,
+                timestamp: current_time,
+            });
+            msg!("Flagged Mint: {} features={:#07b}", mint, flagged);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateListing<'info>>,
+    params: CreateListingParams,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let new_listing = build_listing(
+        &ListingContext {
+            maker: ctx.accounts.maker.key(),
+            maker_profile: &ctx.accounts.maker_profile,
+            platform: &ctx.accounts.platform,
+            token_mint_source: &ctx.accounts.token_mint_source,
+            token_mint_destination: &ctx.accounts.token_mint_destination,
+            source_whitelist: ctx.accounts.source_whitelist.as_ref(),
+            dest_whitelist: ctx.accounts.dest_whitelist.as_ref(),
+            price_feed: ctx.accounts.price_feed.as_ref(),
+        },
+        &params,
+        ctx.bumps.listing,
+        current_time,
+        ctx.program_id,
     )?;
 
-    // Transfer tokens to vault
-    transfer_tokens(
-        &ctx.accounts.maker_token_account_source,
+    deposit_to_vault(
+        &ctx.accounts.maker.to_account_info(),
+        ctx.accounts.maker_token_account_source.as_ref(),
         &ctx.accounts.vault,
         &ctx.accounts.token_mint_source,
-        &ctx.accounts.maker.to_account_info(),
-        &ctx.accounts.token_program,
+        &ctx.accounts.token_program_source,
+        &ctx.accounts.system_program,
         params.amount_source,
-        None,
+        ctx.remaining_accounts,
     )?;
 
-    // Calculate expiry
-    let expires_at = calculate_expiry(params.duration_seconds)?;
-
     // Initialize listing
-    let listing = &mut ctx.accounts.listing;
-    listing.id = params.id;
-    listing.maker = ctx.accounts.maker.key();
-    listing.token_mint_source = ctx.accounts.token_mint_source.key();
-    listing.token_mint_destination = ctx.accounts.token_mint_destination.key();
-    listing.amount_source_total = params.amount_source;
-    listing.amount_source_remaining = params.amount_source;
-    listing.amount_destination_total = params.amount_destination;
-    listing.amount_destination_remaining = params.amount_destination;
-    listing.min_fill_amount = params.min_fill_amount;
-    listing.max_slippage_bps = params.max_slippage_bps;
-    listing.expires_at = expires_at;
-    listing.created_at = current_time;
-    listing.updated_at = current_time;
-    listing.status = ListingStatus::Active;
-    listing.fill_count = 0;
-    listing.bump = ctx.bumps.listing;
+    ctx.accounts.listing.set_inner(new_listing.listing.clone());
 
     // Update user profile
-    let profile = &mut ctx.accounts.maker_profile;
-    profile.listings_created = profile
-        .listings_created
-        .checked_add(1)
-        .ok_or(SelixError::ArithmeticOverflow)?;
-    profile.active_listings = profile
-        .active_listings
-        .checked_add(1)
-        .ok_or(SelixError::ArithmeticOverflow)?;
-    profile.last_activity_at = current_time;
+    ctx.accounts
+        .maker_profile
+        .record_listing_created(params.good_till_cancelled, current_time)?;
 
     // Update platform stats
-    let platform = &mut ctx.accounts.platform;
-    platform.total_listings_created = platform
-        .total_listings_created
-        .checked_add(1)
-        .ok_or(SelixError::ArithmeticOverflow)?;
+    ctx.accounts.platform.record_listing_created()?;
 
-    emit!(ListingCreated {
-        listing_id: params.id,
-        maker: ctx.accounts.maker.key(),
-        token_mint_source: ctx.accounts.token_mint_source.key(),
-        token_mint_destination: ctx.accounts.token_mint_destination.key(),
-        amount_source: params.amount_source,
-        amount_destination: params.amount_destination,
-        min_fill_amount: params.min_fill_amount,
-        expires_at,
-        timestamp: current_time,
-    });
+    emit_listing_created(
+        &new_listing,
+        ctx.accounts.platform.flagged_mint_features,
+        current_time,
+    )?;
+
+    let listing = &new_listing.listing;
+    let (visible_amount_source, visible_amount_destination) = visible_amounts(listing)?;
 
     msg!("LISTING CREATED");
     msg!("------------------");
-    msg!("Listing ID: {}", params.id);
-    msg!("Maker: {}", ctx.accounts.maker.key());
-    msg!("Source Token: {}", ctx.accounts.token_mint_source.key());
-    msg!(
-        "Destination Token: {}",
-        ctx.accounts.token_mint_destination.key()
-    );
-    msg!("Amount Source: {}", params.amount_source);
-    msg!("Amount Destination: {}", params.amount_destination);
-    msg!("Min Fill: {}", params.min_fill_amount);
-    msg!("Expires At: {}", expires_at);
+    msg!("Listing ID: {}", listing.id);
+    msg!("Maker: {}", listing.maker);
+    msg!("Source Token: {}", listing.token_mint_source);
+    msg!("Destination Token: {}", listing.token_mint_destination);
+    msg!("Side: {:?}", listing.side);
+    if listing.source_is_nft || listing.destination_is_nft {
+        msg!("NFT Source: {}", listing.source_is_nft);
+        msg!("NFT Destination: {}", listing.destination_is_nft);
+    }
+    if listing.destination_is_collection {
+        msg!(
+            "Any NFT From Collection: {}",
+            listing.token_mint_destination
+        );
+    }
+    msg!("Amount Source: {}", visible_amount_source);
+    if new_listing.source_transfer_fee > 0 {
+        msg!("Source Transfer Fee: {}", new_listing.source_transfer_fee);
+    }
+    msg!("Amount Destination: {}", visible_amount_destination);
+    msg!("Min Fill: {}", listing.min_fill_amount);
+    msg!("All Or None: {}", listing.all_or_none);
+    msg!("Kind: {:?}", listing.kind);
+    if listing.kind == ListingKind::DutchAuction {
+        msg!(
+            "Auction Floor: {}",
+            listing.auction_floor_amount_destination
+        );
+        msg!("Auction Step: {}s", listing.auction_step_seconds);
+    }
+    if listing.price_feed != Pubkey::default() {
+        msg!("Price Feed: {}", listing.price_feed);
+    }
+    if listing.kind == ListingKind::OraclePegged {
+        msg!("Oracle Premium BPS: {}", listing.oracle_premium_bps);
+    }
+    if listing.trigger_condition != TriggerCondition::None {
+        msg!(
+            "Trigger: {:?} {}",
+            listing.trigger_condition,
+            listing.trigger_price
+        );
+    }
+    msg!("Allowed Takers: {}", listing.allowed_takers.len());
+    msg!("Starts At: {}", listing.starts_at);
+    if listing.good_till_cancelled {
+        msg!("Expires At: Never (good-till-cancelled)");
+    } else {
+        msg!("Expires At: {}", listing.expires_at);
+    }
+    if params.release_schedule.is_some() {
+        msg!("Release Cliff At: {}", listing.release_cliff_at);
+        msg!("Release End At: {}", listing.release_end_at);
+    }
     msg!("Timestamp: {}", current_time);
 
     Ok(())
@@ -0,0 +1,208 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    instructions::listing::create_listing::{
+        build_listing, deposit_to_vault, emit_listing_created, CreateListingParams, ListingContext,
+    },
+    state::{Listing, Platform, TokenWhitelist, UserProfile},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Accounts passed in `remaining_accounts` for every listing, in order:
+/// listing PDA and vault (the listing's associated token account), both
+/// uninitialized
+pub const BATCH_CREATE_ACCOUNTS: usize = 2;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateListingsBatchParams {
+    /// One entry per listing/vault group in `remaining_accounts`
+    pub listings: Vec<CreateListingParams>,
+}
+
+#[derive(Accounts)]
+pub struct CreateListingsBatch<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Optional: omitted when the source is native SOL
+    #[account(
+        mut,
+        associated_token::mint = token_mint_source,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program_source,
+    )]
+    pub maker_token_account_source: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program_source)]
+    pub token_mint_source: InterfaceAccount<'info, Mint>,
+
+    #[account(mint::token_program = token_program_destination)]
+    pub token_mint_destination: InterfaceAccount<'info, Mint>,
+
+    /// Optional: required when platform.whitelist_enabled is true
+    pub source_whitelist: Option<Account<'info, TokenWhitelist>>,
+
+    /// Optional: required when platform.whitelist_enabled is true
+    pub dest_whitelist: Option<Account<'info, TokenWhitelist>>,
+
+    /// CHECK: Optional: required for oracle-pegged and triggered listings.
+    /// Validated as a Pyth price update account in the handler.
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    pub token_program_source: Interface<'info, TokenInterface>,
+    pub token_program_destination: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateListingsBatch<'info>>,
+    params: CreateListingsBatchParams,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate batch shape
+    let listing_count = params.listings.len();
+    require!(
+        (1..=MAX_BATCH_LISTINGS).contains(&listing_count),
+        SelixError::InvalidListingBatch
+    );
+    let listing_accounts_len = listing_count * BATCH_CREATE_ACCOUNTS;
+    require!(
+        ctx.remaining_accounts.len() >= listing_accounts_len,
+        SelixError::InvalidListingBatch
+    );
+    // Accounts after the listings are extra accounts for transfer-hook mints
+    let (listing_accounts, hook_accounts) = ctx.remaining_accounts.split_at(listing_accounts_len);
+
+    let maker_key = ctx.accounts.maker.key();
+    let maker = ctx.accounts.maker.to_account_info();
+
+    for (listing_params, accounts) in params
+        .listings
+        .iter()
+        .zip(listing_accounts.chunks(BATCH_CREATE_ACCOUNTS))
+    {
+        let listing_info = &accounts[0];
+        let vault_info = &accounts[1];
+
+        // Validate the listing PDA
+        let (listing_key, bump) = derive_listing_pda(&maker_key, listing_params.id);
+        require_keys_eq!(listing_info.key(), listing_key, SelixError::InvalidPDA);
+
+        // Same validation as create_listing, against the running listing counts
+        let new_listing = build_listing(
+            &ListingContext {
+                maker: maker_key,
+                maker_profile: &ctx.accounts.maker_profile,
+                platform: &ctx.accounts.platform,
+                token_mint_source: &ctx.accounts.token_mint_source,
+                token_mint_destination: &ctx.accounts.token_mint_destination,
+                source_whitelist: ctx.accounts.source_whitelist.as_ref(),
+                dest_whitelist: ctx.accounts.dest_whitelist.as_ref(),
+                price_feed: ctx.accounts.price_feed.as_ref(),
+            },
+            listing_params,
+            bump,
+            current_time,
+            ctx.program_id,
+        )?;
+
+        // Create the listing account
+        let id_bytes = listing_params.id.to_le_bytes();
+        let listing_seeds: &[&[u8]] = &[LISTING_SEED, maker_key.as_ref(), &id_bytes, &[bump]];
+        create_pda_account(
+            &maker,
+            listing_info,
+            8 + Listing::INIT_SPACE,
+            &[listing_seeds],
+            &ctx.accounts.system_program,
+            ctx.program_id,
+        )?;
+
+        // Create the vault owned by the listing
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: maker.clone(),
+                associated_token: vault_info.clone(),
+                authority: listing_info.clone(),
+                mint: ctx.accounts.token_mint_source.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program_source.to_account_info(),
+            },
+        ))?;
+        let vault: InterfaceAccount<'info, TokenAccount> = InterfaceAccount::try_from(vault_info)?;
+
+        deposit_to_vault(
+            &maker,
+            ctx.accounts.maker_token_account_source.as_ref(),
+            &vault,
+            &ctx.accounts.token_mint_source,
+            &ctx.accounts.token_program_source,
+            &ctx.accounts.system_program,
+            listing_params.amount_source,
+            hook_accounts,
+        )?;
+
+        // Initialize listing
+        new_listing
+            .listing
+            .try_serialize(&mut &mut listing_info.try_borrow_mut_data()?[..])?;
+
+        // Update user profile and platform stats
+        ctx.accounts
+            .maker_profile
+            .record_listing_created(listing_params.good_till_cancelled, current_time)?;
+        ctx.accounts.platform.record_listing_created()?;
+
+        emit_listing_created(
+            &new_listing,
+            ctx.accounts.platform.flagged_mint_features,
+            current_time,
+        )?;
+
+        msg!(
+            "LISTING CREATED: id={} source={} destination={}",
+            listing_params.id,
+            new_listing.listing.amount_source_total,
+            new_listing.listing.amount_destination_total
+        );
+    }
+
+    msg!("LISTINGS BATCH CREATED");
+    msg!("------------------");
+    msg!("Maker: {}", maker_key);
+    msg!("Source Token: {}", ctx.accounts.token_mint_source.key());
+    msg!(
+        "Destination Token: {}",
+        ctx.accounts.token_mint_destination.key()
+    );
+    msg!("Listings: {}", listing_count);
+    msg!(
+        "Active Listings: {}",
+        ctx.accounts.maker_profile.active_listings
+    );
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
@@ -1,11 +1,21 @@
 #![allow(ambiguous_glob_reexports)]
 
+pub mod activate_listing;
+pub mod cancel_all_listings;
 pub mod cancel_listing;
 pub mod close_expired;
 pub mod create_listing;
+pub mod create_listings_batch;
+pub mod resize_listing;
+pub mod trigger_listing;
 pub mod update_listing;
 
+pub use activate_listing::*;
+pub use cancel_all_listings::*;
 pub use cancel_listing::*;
 pub use close_expired::*;
 pub use create_listing::*;
+pub use create_listings_batch::*;
+pub use resize_listing::*;
+pub use trigger_listing::*;
 pub use update_listing::*;
@@ -0,0 +1,259 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::ListingResized,
+    state::{Listing, ListingStatus, Platform},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct ResizeListing<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, maker.key().as_ref(), &listing.id.to_le_bytes()],
+        bump = listing.bump,
+        has_one = maker @ SelixError::UnauthorizedAuthority,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_source,
+        associated_token::authority = listing,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Optional: omitted when topping up with native SOL.
+    /// Withdrawals of native SOL unwrap through this account.
+    #[account(
+        init_if_needed,
+        payer = maker,
+        associated_token::mint = token_mint_source,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_token_account_source: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint_source.key() == listing.token_mint_source
+            @ SelixError::TokenAccountMintMismatch,
+    )]
+    pub token_mint_source: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+fn validate_resizable(listing: &Listing, platform: &Platform, current_time: i64) -> Result<()> {
+    // Validate platform not paused
+    validate_not_paused(platform)?;
+
+    // Validate listing is pending, active or partially filled
+    require!(
+        listing.status == ListingStatus::Pending
+            || listing.status == ListingStatus::Active
+            || listing.status == ListingStatus::PartiallyFilled,
+        SelixError::InvalidListingStatus
+    );
+
+    // Validate not expired
+    require!(
+        !listing.is_expired(current_time),
+        SelixError::ListingExpired
+    );
+
+    // NFT listings always trade exactly one NFT
+    require!(
+        !listing.source_is_nft && !listing.destination_is_nft,
+        SelixError::InvalidNftAmount
+    );
+
+    Ok(())
+}
+
+pub fn top_up_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResizeListing<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    validate_resizable(&ctx.accounts.listing, &ctx.accounts.platform, current_time)?;
+    require!(amount > 0, SelixError::InvalidAmount);
+
+    if is_native_mint(&ctx.accounts.token_mint_source.key()) {
+        // Wrap native SOL straight into the vault
+        require!(
+            ctx.accounts.maker.lamports() >= amount,
+            SelixError::InsufficientMakerBalance
+        );
+
+        wrap_sol(
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.vault,
+            &ctx.accounts.system_program,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+    } else {
+        let maker_token_account_source = ctx
+            .accounts
+            .maker_token_account_source
+            .as_ref()
+            .ok_or(SelixError::InvalidTokenAccount)?;
+
+        check_sufficient_balance(maker_token_account_source, amount)?;
+
+        transfer_tokens(
+            maker_token_account_source,
+            &ctx.accounts.vault,
+            &ctx.accounts.token_mint_source,
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.token_program,
+            amount,
+            None,
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    // Grow the listing by what the vault received after transfer fees
+    let amount_received = amount
+        .checked_sub(calculate_transfer_fee(
+            &ctx.accounts.token_mint_source,
+            amount,
+        )?)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+    let listing = &mut ctx.accounts.listing;
+    let amount_destination = listing.increase_inventory(amount_received)?;
+    listing.updated_at = current_time;
+
+    emit!(ListingResized {
+        listing_id: listing.id,
+        maker: ctx.accounts.maker.key(),
+        is_increase: true,
+        amount_source: amount_received,
+        amount_destination,
+        new_amount_source_remaining: listing.amount_source_remaining,
+        new_amount_destination_remaining: listing.amount_destination_remaining,
+        timestamp: current_time,
+    });
+
+    // Listing audit log
+    msg!("LISTING TOPPED UP");
+    msg!("--------------------");
+    msg!("Listing ID: {}", listing.id);
+    msg!("Maker: {}", ctx.accounts.maker.key());
+    msg!("Amount Source Added: {}", amount_received);
+    msg!("Amount Destination Added: {}", amount_destination);
+    msg!("Remaining Source: {}", listing.amount_source_remaining);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
+
+pub fn withdraw_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResizeListing<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    validate_resizable(&ctx.accounts.listing, &ctx.accounts.platform, current_time)?;
+    require!(amount > 0, SelixError::InvalidAmount);
+
+    // Shrink the listing at the same price
+    let listing = &mut ctx.accounts.listing;
+    let amount_destination = listing.decrease_inventory(amount)?;
+    listing.updated_at = current_time;
+
+    // Validate the remaining inventory is not dust
+    require!(
+        listing.amount_source_remaining >= ctx.accounts.platform.min_trade_amount,
+        SelixError::AmountTooSmall
+    );
+
+    let listing_id = listing.id;
+    let new_amount_source_remaining = listing.amount_source_remaining;
+    let new_amount_destination_remaining = listing.amount_destination_remaining;
+
+    let maker_key = ctx.accounts.maker.key();
+    let id_bytes = listing_id.to_le_bytes();
+    let listing_seeds: &[&[u8]] = &[
+        LISTING_SEED,
+        maker_key.as_ref(),
+        &id_bytes,
+        &[ctx.accounts.listing.bump],
+    ];
+    let signer_seeds = &[listing_seeds];
+
+    let maker_token_account_source = ctx
+        .accounts
+        .maker_token_account_source
+        .as_ref()
+        .ok_or(SelixError::InvalidTokenAccount)?;
+
+    // wSOL the maker already held must stay wrapped, so only an empty
+    // (freshly created) account is closed to unwrap the withdrawal
+    let unwrap_source = is_native_mint(&ctx.accounts.token_mint_source.key())
+        && maker_token_account_source.amount == 0;
+
+    transfer_tokens(
+        &ctx.accounts.vault,
+        maker_token_account_source,
+        &ctx.accounts.token_mint_source,
+        &ctx.accounts.listing.to_account_info(),
+        &ctx.accounts.token_program,
+        amount,
+        Some(signer_seeds),
+        ctx.remaining_accounts,
+    )?;
+
+    // Unwrap native SOL by closing the maker's temporary wSOL account
+    if unwrap_source {
+        close_token_account(
+            maker_token_account_source,
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.token_program,
+            None,
+        )?;
+    }
+
+    emit!(ListingResized {
+        listing_id,
+        maker: maker_key,
+        is_increase: false,
+        amount_source: amount,
+        amount_destination,
+        new_amount_source_remaining,
+        new_amount_destination_remaining,
+        timestamp: current_time,
+    });
+
+    // Listing audit log
+    msg!("LISTING WITHDRAWN");
+    msg!("--------------------");
+    msg!("Listing ID: {}", listing_id);
+    msg!("Maker: {}", maker_key);
+    msg!("Amount Source Withdrawn: {}", amount);
+    msg!("Amount Destination Removed: {}", amount_destination);
+    msg!("Remaining Source: {}", new_amount_source_remaining);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
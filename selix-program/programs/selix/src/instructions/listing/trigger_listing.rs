@@ -0,0 +1,91 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::ListingTriggered,
+    state::{Listing, ListingStatus, Platform},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct TriggerListing<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, listing.maker.as_ref(), &listing.id.to_le_bytes()],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// CHECK: Must match listing.price_feed.
+    /// Validated as a Pyth price update account in the handler.
+    #[account(address = listing.price_feed @ SelixError::InvalidPriceFeed)]
+    pub price_feed: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<TriggerListing>) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate platform not paused
+    validate_not_paused(&ctx.accounts.platform)?;
+
+    // Validate listing is pending on a trigger
+    require!(
+        listing.status == ListingStatus::Pending,
+        SelixError::InvalidListingStatus
+    );
+    require!(listing.has_trigger(), SelixError::InvalidTrigger);
+
+    // Validate start time has passed
+    require!(
+        current_time >= listing.starts_at,
+        SelixError::ListingNotStarted
+    );
+
+    // Validate not expired
+    require!(
+        !listing.is_expired(current_time),
+        SelixError::ListingExpired
+    );
+
+    // Validate the oracle price has crossed the trigger
+    let price = load_oracle_price(
+        &ctx.accounts.price_feed,
+        Some(&listing.price_feed_id),
+        current_time,
+    )?;
+    require!(listing.is_triggered(&price), SelixError::TriggerNotMet);
+
+    listing.status = ListingStatus::Active;
+    listing.updated_at = current_time;
+
+    emit!(ListingTriggered {
+        listing_id: listing.id,
+        maker: listing.maker,
+        triggered_by: ctx.accounts.caller.key(),
+        oracle_price: price.price,
+        trigger_price: listing.trigger_price,
+        timestamp: current_time,
+    });
+
+    // Listing audit log
+    msg!("LISTING TRIGGERED");
+    msg!("--------------------");
+    msg!("Listing ID: {}", listing.id);
+    msg!("Maker: {}", listing.maker);
+    msg!("Triggered By: {}", ctx.accounts.caller.key());
+    msg!("Condition: {:?}", listing.trigger_condition);
+    msg!("Trigger Price: {}", listing.trigger_price);
+    msg!("Oracle Price: {}", price.price);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::{
     constants::*,
     errors::SelixError,
     events::ListingUpdated,
-    state::{Listing, ListingStatus, Platform},
+    state::{Listing, ListingKind, ListingStatus, Platform},
     utils::*,
 };
 use anchor_lang::prelude::*;
@@ -13,6 +13,8 @@ pub struct UpdateListingParams {
     pub new_min_fill_amount: Option<u64>,
     pub new_max_slippage_bps: Option<u16>,
     pub extend_duration_seconds: Option<i64>,
+    pub new_allowed_takers: Option<Vec<Pubkey>>,
+    pub new_oracle_premium_bps: Option<i16>,
 }
 
 #[derive(Accounts)]
@@ -43,20 +45,47 @@ pub fn handler(ctx: Context<UpdateListing>, params: UpdateListingParams) -> Resu
     // Validate platform not paused
     validate_not_paused(platform)?;
 
-    // Validate listing is active or partially filled
+    // Validate listing is pending, active or partially filled
     require!(
-        listing.status == ListingStatus::Active || listing.status == ListingStatus::PartiallyFilled,
+        listing.status == ListingStatus::Pending
+            || listing.status == ListingStatus::Active
+            || listing.status == ListingStatus::PartiallyFilled,
         SelixError::InvalidListingStatus
     );
 
     // Validate not expired
-    require!(!is_expired(listing.expires_at)?, SelixError::ListingExpired);
+    require!(
+        !listing.is_expired(current_time),
+        SelixError::ListingExpired
+    );
+
+    // Dutch auction curves are fixed at creation
+    require!(
+        listing.kind != ListingKind::DutchAuction
+            || (params.new_amount_destination.is_none()
+                && params.extend_duration_seconds.is_none()),
+        SelixError::UnsupportedListingKind
+    );
+
+    // Oracle-pegged listings are repriced through the premium only
+    require!(
+        listing.kind != ListingKind::OraclePegged || params.new_amount_destination.is_none(),
+        SelixError::UnsupportedListingKind
+    );
+    require!(
+        listing.kind == ListingKind::OraclePegged || params.new_oracle_premium_bps.is_none(),
+        SelixError::UnsupportedListingKind
+    );
 
     let old_amount_destination = listing.amount_destination_remaining;
 
     // Update destination amount if provided
     if let Some(new_dest) = params.new_amount_destination {
-        validate_amount(new_dest, platform.min_trade_amount)?;
+        validate_trade_amount(
+            new_dest,
+            listing.destination_is_nft,
+            platform.min_trade_amount,
+        )?;
 
         // Calculate proportional remaining based on filled amount
         let filled_ratio = (listing.amount_source_total - listing.amount_source_remaining) as u128;
@@ -81,6 +110,9 @@ pub fn handler(ctx: Context<UpdateListing>, params: UpdateListingParams) -> Resu
     // Update min fill amount if provided
     if let Some(new_min_fill) = params.new_min_fill_amount {
         validate_min_fill_amount(new_min_fill, listing.amount_source_remaining)?;
+        if listing.is_iceberg() {
+            validate_min_fill_amount(new_min_fill, listing.display_amount)?;
+        }
         listing.min_fill_amount = new_min_fill;
     }
 
@@ -92,6 +124,8 @@ pub fn handler(ctx: Context<UpdateListing>, params: UpdateListingParams) -> Resu
 
     // Extend duration if provided
     if let Some(extend_duration) = params.extend_duration_seconds {
+        require!(!listing.good_till_cancelled, SelixError::ListingHasNoExpiry);
+
         let new_expiry = listing
             .expires_at
             .checked_add(extend_duration)
@@ -107,6 +141,18 @@ pub fn handler(ctx: Context<UpdateListing>, params: UpdateListingParams) -> Resu
         listing.expires_at = new_expiry;
     }
 
+    // Update oracle premium if provided
+    if let Some(new_premium_bps) = params.new_oracle_premium_bps {
+        validate_oracle_premium(new_premium_bps)?;
+        listing.oracle_premium_bps = new_premium_bps;
+    }
+
+    // Replace private listing taker set if provided
+    if let Some(new_allowed_takers) = &params.new_allowed_takers {
+        validate_allowed_takers(new_allowed_takers, &ctx.accounts.maker.key())?;
+        listing.allowed_takers = new_allowed_takers.clone();
+    }
+
     listing.updated_at = current_time;
 
     emit!(ListingUpdated {
@@ -138,6 +184,12 @@ pub fn handler(ctx: Context<UpdateListing>, params: UpdateListingParams) -> Resu
     if params.extend_duration_seconds.is_some() {
         msg!("New Expiry: {}", listing.expires_at);
     }
+    if params.new_oracle_premium_bps.is_some() {
+        msg!("New Oracle Premium BPS: {}", listing.oracle_premium_bps);
+    }
+    if params.new_allowed_takers.is_some() {
+        msg!("Allowed Takers: {}", listing.allowed_takers.len());
+    }
     msg!("Timestamp: {}", current_time);
 
     Ok(())
@@ -2,10 +2,14 @@
 
 pub mod admin;
 pub mod listing;
+pub mod offer;
+pub mod signed_order;
 pub mod trading;
 pub mod user;
 
 pub use admin::*;
 pub use listing::*;
+pub use offer::*;
+pub use signed_order::*;
 pub use trading::*;
 pub use user::*;
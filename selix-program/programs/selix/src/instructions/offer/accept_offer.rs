@@ -0,0 +1,417 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::{OfferAccepted, SwapExecuted},
+    state::{Listing, ListingStatus, Offer, Platform, UserProfile},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct AcceptOffer<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    /// CHECK: Taker receives source tokens and offer rent.
+    /// Validated via has_one on offer.
+    #[account(mut)]
+    pub taker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, taker.key().as_ref()],
+        bump = taker_profile.bump,
+    )]
+    pub taker_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Box<Account<'info, Platform>>,
+
+    /// CHECK: Fee collector wallet. Validated against platform state.
+    #[account(
+        mut,
+        constraint = fee_collector.key() == platform.fee_collector
+            @ SelixError::UnauthorizedAuthority
+    )]
+    pub fee_collector: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, maker.key().as_ref(), &listing.id.to_le_bytes()],
+        bump = listing.bump,
+        has_one = maker @ SelixError::UnauthorizedAuthority,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_source,
+        associated_token::authority = listing,
+        associated_token::token_program = token_program_source,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = taker,
+        seeds = [
+            OFFER_SEED,
+            listing.key().as_ref(),
+            taker.key().as_ref(),
+            &offer.id.to_le_bytes(),
+        ],
+        bump = offer.bump,
+        has_one = listing @ SelixError::OfferListingMismatch,
+        has_one = taker,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program_destination,
+    )]
+    pub offer_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_source,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program_source,
+    )]
+    pub taker_token_account_source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Optional: omitted when the destination is native SOL
+    #[account(
+        mut,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program_destination,
+    )]
+    pub maker_token_account_destination: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Optional: omitted when the destination is native SOL
+    #[account(
+        init_if_needed,
+        payer = maker,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = fee_collector,
+        associated_token::token_program = token_program_destination,
+    )]
+    pub fee_collector_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Writable so withheld transfer fees can be harvested before the vault closes
+    #[account(
+        mut,
+        mint::token_program = token_program_source,
+        constraint = token_mint_source.key() == listing.token_mint_source
+            @ SelixError::TokenAccountMintMismatch,
+    )]
+    pub token_mint_source: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Writable so withheld transfer fees can be harvested before the offer vault closes
+    #[account(
+        mut,
+        mint::token_program = token_program_destination,
+        constraint = token_mint_destination.key() == listing.token_mint_destination
+            @ SelixError::TokenAccountMintMismatch,
+    )]
+    pub token_mint_destination: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program_source: Interface<'info, TokenInterface>,
+    pub token_program_destination: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate platform not paused
+    validate_not_paused(&ctx.accounts.platform)?;
+
+    let listing = &ctx.accounts.listing;
+    let offer = &ctx.accounts.offer;
+
+    // Validate listing is tradeable
+    require!(
+        listing.status != ListingStatus::Pending,
+        SelixError::ListingNotStarted
+    );
+    require!(listing.status.can_be_traded(), SelixError::ListingNotActive);
+    require!(
+        !listing.is_expired(current_time),
+        SelixError::ListingExpired
+    );
+    listing.validate_cancel_epoch(
+        ctx.accounts
+            .maker_profile
+            .as_deref()
+            .map(|profile| &**profile),
+    )?;
+
+    // Validate offer is still open
+    require!(!offer.is_expired(current_time), SelixError::OfferExpired);
+
+    // Validate taker is still allowed on private listings
+    require!(
+        listing.is_taker_allowed(&offer.taker),
+        SelixError::TakerNotAllowed
+    );
+
+    // Validate the requested amount against the listing's current inventory
+    listing.validate_fill_amount(offer.amount_source, current_time)?;
+
+    // Offers are priced by the taker; the rest of the listing keeps its price
+    let amount_source = offer.amount_source;
+    let amount_destination = offer.amount_destination;
+    let (_, destination_consumed) = calculate_partial_amounts(
+        listing.amount_source_remaining,
+        listing.amount_destination_remaining,
+        amount_source,
+    )?;
+
+    // Volume is accounted in the quote token for both sides
+    let listing_side = listing.side;
+    let listing_good_till_cancelled = listing.good_till_cancelled;
+    let quote_volume = listing.quote_amount(amount_source, amount_destination);
+
+    // Calculate fee
+    let fee_amount = calculate_fee(amount_destination, ctx.accounts.platform.fee_basis_points)?;
+    let amount_to_maker = amount_destination
+        .checked_sub(fee_amount)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+
+    // Token-2022 transfer fees withheld from each leg of the swap
+    let source_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.token_mint_source, amount_source)?;
+    let maker_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.token_mint_destination, amount_to_maker)?;
+    let fee_collector_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.token_mint_destination, fee_amount)?;
+    let destination_transfer_fee = maker_transfer_fee
+        .checked_add(fee_collector_transfer_fee)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+
+    let destination_is_native = is_native_mint(&ctx.accounts.token_mint_destination.key());
+
+    // Build listing and offer PDA signer seeds
+    let maker_key = ctx.accounts.maker.key();
+    let listing_id = listing.id;
+    let listing_id_bytes = listing_id.to_le_bytes();
+    let listing_seeds: &[&[u8]] = &[
+        LISTING_SEED,
+        maker_key.as_ref(),
+        &listing_id_bytes,
+        &[listing.bump],
+    ];
+    let listing_signer_seeds = &[listing_seeds];
+
+    let listing_key = listing.key();
+    let taker_key = ctx.accounts.taker.key();
+    let offer_id = offer.id;
+    let offer_id_bytes = offer_id.to_le_bytes();
+    let offer_seeds: &[&[u8]] = &[
+        OFFER_SEED,
+        listing_key.as_ref(),
+        taker_key.as_ref(),
+        &offer_id_bytes,
+        &[offer.bump],
+    ];
+    let offer_signer_seeds = &[offer_seeds];
+
+    let listing_account_info = listing.to_account_info();
+    let offer_account_info = offer.to_account_info();
+
+    // 1. Vault → Taker (source tokens; native SOL is delivered as wSOL)
+    transfer_tokens(
+        &ctx.accounts.vault,
+        &ctx.accounts.taker_token_account_source,
+        &ctx.accounts.token_mint_source,
+        &listing_account_info,
+        &ctx.accounts.token_program_source,
+        amount_source,
+        Some(listing_signer_seeds),
+        ctx.remaining_accounts,
+    )?;
+
+    if destination_is_native {
+        // 2. Offer vault → Maker (unwraps the escrow and its rent to the maker)
+        let offer_vault_lamports = ctx.accounts.offer_vault.to_account_info().lamports();
+        close_token_account(
+            &ctx.accounts.offer_vault,
+            &ctx.accounts.maker.to_account_info(),
+            &offer_account_info,
+            &ctx.accounts.token_program_destination,
+            Some(offer_signer_seeds),
+        )?;
+
+        // 3. Maker → Fee collector
+        if fee_amount > 0 {
+            transfer_lamports(
+                &ctx.accounts.maker.to_account_info(),
+                &ctx.accounts.fee_collector.to_account_info(),
+                &ctx.accounts.system_program,
+                fee_amount,
+            )?;
+        }
+
+        // Return the offer vault rent to the taker
+        let vault_rent = offer_vault_lamports
+            .checked_sub(amount_destination)
+            .ok_or(SelixError::ArithmeticUnderflow)?;
+        transfer_lamports(
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.taker.to_account_info(),
+            &ctx.accounts.system_program,
+            vault_rent,
+        )?;
+    } else {
+        // 2. Offer vault → Maker (destination tokens minus fee)
+        transfer_tokens(
+            &ctx.accounts.offer_vault,
+            ctx.accounts
+                .maker_token_account_destination
+                .as_ref()
+                .ok_or(SelixError::InvalidTokenAccount)?,
+            &ctx.accounts.token_mint_destination,
+            &offer_account_info,
+            &ctx.accounts.token_program_destination,
+            amount_to_maker,
+            Some(offer_signer_seeds),
+            ctx.remaining_accounts,
+        )?;
+
+        // 3. Offer vault → Fee collector
+        if fee_amount > 0 {
+            transfer_tokens(
+                &ctx.accounts.offer_vault,
+                ctx.accounts
+                    .fee_collector_token_account
+                    .as_ref()
+                    .ok_or(SelixError::InvalidTokenAccount)?,
+                &ctx.accounts.token_mint_destination,
+                &offer_account_info,
+                &ctx.accounts.token_program_destination,
+                fee_amount,
+                Some(offer_signer_seeds),
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        // Close the empty offer vault to the taker
+        harvest_withheld_fees(
+            &ctx.accounts.offer_vault,
+            &ctx.accounts.token_mint_destination,
+            &ctx.accounts.token_program_destination,
+        )?;
+        close_token_account(
+            &ctx.accounts.offer_vault,
+            &ctx.accounts.taker.to_account_info(),
+            &offer_account_info,
+            &ctx.accounts.token_program_destination,
+            Some(offer_signer_seeds),
+        )?;
+    }
+
+    // Update listing state
+    let listing = &mut ctx.accounts.listing;
+    let is_partial = listing.apply_fill(amount_source, destination_consumed, current_time)?;
+
+    // Update platform stats
+    ctx.accounts
+        .platform
+        .record_swap(quote_volume, fee_amount)?;
+
+    // Update taker profile
+    if let Some(taker_profile) = &mut ctx.accounts.taker_profile {
+        taker_profile.record_swap_as_taker(quote_volume, fee_amount, current_time)?;
+    }
+
+    // Update maker profile
+    if let Some(maker_profile) = &mut ctx.accounts.maker_profile {
+        maker_profile.record_swap_as_maker(quote_volume, current_time)?;
+
+        if !is_partial {
+            maker_profile.record_listing_closed(listing_good_till_cancelled);
+        }
+    }
+
+    // Close vault if fully filled
+    if !is_partial {
+        harvest_withheld_fees(
+            &ctx.accounts.vault,
+            &ctx.accounts.token_mint_source,
+            &ctx.accounts.token_program_source,
+        )?;
+        close_token_account(
+            &ctx.accounts.vault,
+            &ctx.accounts.maker.to_account_info(),
+            &listing_account_info,
+            &ctx.accounts.token_program_source,
+            Some(listing_signer_seeds),
+        )?;
+    }
+
+    emit!(SwapExecuted {
+        listing_id,
+        maker: maker_key,
+        taker: taker_key,
+        token_mint_source: ctx.accounts.token_mint_source.key(),
+        token_mint_destination: ctx.accounts.token_mint_destination.key(),
+        side: listing_side,
+        amount_source,
+        amount_destination,
+        quote_volume,
+        clearing_rate: calculate_rate(amount_source, amount_destination)?,
+        fee_amount,
+        source_transfer_fee,
+        destination_transfer_fee,
+        is_partial,
+        remaining_source: listing.visible_amount_source(),
+        new_status: listing.status,
+        timestamp: current_time,
+    });
+
+    emit!(OfferAccepted {
+        offer: ctx.accounts.offer.key(),
+        offer_id,
+        listing_id,
+        maker: maker_key,
+        taker: taker_key,
+        amount_source,
+        amount_destination,
+        fee_amount,
+        timestamp: current_time,
+    });
+
+    // Offer audit log
+    msg!("OFFER ACCEPTED");
+    msg!("------------------");
+    msg!("Offer ID: {}", offer_id);
+    msg!("Listing ID: {}", listing_id);
+    msg!("Maker: {}", maker_key);
+    msg!("Taker: {}", taker_key);
+    msg!("Amount Source: {}", amount_source);
+    msg!("Amount Destination: {}", amount_destination);
+    msg!("Fee: {}", fee_amount);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
@@ -0,0 +1,235 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::OfferMade,
+    state::{Listing, Offer, Platform},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MakeOfferParams {
+    pub id: u64,
+    /// Source tokens requested from the listing
+    pub amount_source: u64,
+    /// Destination tokens escrowed and proposed as payment
+    pub amount_destination: u64,
+    pub duration_seconds: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MakeOfferParams)]
+pub struct MakeOffer<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Box<Account<'info, Platform>>,
+
+    #[account(
+        seeds = [LISTING_SEED, listing.maker.as_ref(), &listing.id.to_le_bytes()],
+        bump = listing.bump,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        init,
+        payer = taker,
+        space = 8 + Offer::INIT_SPACE,
+        seeds = [
+            OFFER_SEED,
+            listing.key().as_ref(),
+            taker.key().as_ref(),
+            &params.id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    #[account(
+        init,
+        payer = taker,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program_destination,
+    )]
+    pub offer_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives the source tokens when the offer is accepted
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_source,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program_source,
+    )]
+    pub taker_token_account_source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Optional: omitted when the destination is native SOL
+    #[account(
+        mut,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program_destination,
+    )]
+    pub taker_token_account_destination: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mint::token_program = token_program_source,
+        constraint = token_mint_source.key() == listing.token_mint_source
+            @ SelixError::TokenAccountMintMismatch,
+    )]
+    pub token_mint_source: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mint::token_program = token_program_destination,
+        constraint = token_mint_destination.key() == listing.token_mint_destination
+            @ SelixError::TokenAccountMintMismatch,
+    )]
+    pub token_mint_destination: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program_source: Interface<'info, TokenInterface>,
+    pub token_program_destination: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MakeOffer<'info>>,
+    params: MakeOfferParams,
+) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let platform = &ctx.accounts.platform;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate platform not paused
+    validate_not_paused(platform)?;
+
+    // Validate listing is tradeable
+    require!(listing.status.can_be_traded(), SelixError::ListingNotActive);
+    require!(
+        !listing.is_expired(current_time),
+        SelixError::ListingExpired
+    );
+
+    // Validate taker
+    require_keys_neq!(
+        ctx.accounts.taker.key(),
+        listing.maker,
+        SelixError::CannotSwapOwnListing
+    );
+    require!(
+        listing.is_taker_allowed(&ctx.accounts.taker.key()),
+        SelixError::TakerNotAllowed
+    );
+
+    // Collection listings take a specific NFT, which an offer vault cannot hold
+    require!(
+        !listing.destination_is_collection,
+        SelixError::UnsupportedListingKind
+    );
+
+    // Token-2022 transfer fees are withheld on the way into the offer vault,
+    // so the offer only pays what the vault actually receives
+    let destination_transfer_fee = calculate_transfer_fee(
+        &ctx.accounts.token_mint_destination,
+        params.amount_destination,
+    )?;
+    let amount_destination = params
+        .amount_destination
+        .checked_sub(destination_transfer_fee)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+
+    // Validate amounts and duration
+    listing.validate_fill_amount(params.amount_source, current_time)?;
+    validate_trade_amount(
+        amount_destination,
+        listing.destination_is_nft,
+        platform.min_trade_amount,
+    )?;
+    validate_duration(params.duration_seconds, platform)?;
+
+    // Escrow the proposed payment
+    if is_native_mint(&ctx.accounts.token_mint_destination.key()) {
+        wrap_sol(
+            &ctx.accounts.taker.to_account_info(),
+            &ctx.accounts.offer_vault,
+            &ctx.accounts.system_program,
+            &ctx.accounts.token_program_destination,
+            params.amount_destination,
+        )?;
+    } else {
+        let taker_token_account_destination = ctx
+            .accounts
+            .taker_token_account_destination
+            .as_ref()
+            .ok_or(SelixError::InvalidTokenAccount)?;
+        require!(
+            taker_token_account_destination.amount >= params.amount_destination,
+            SelixError::InsufficientTakerBalance
+        );
+
+        transfer_tokens(
+            taker_token_account_destination,
+            &ctx.accounts.offer_vault,
+            &ctx.accounts.token_mint_destination,
+            &ctx.accounts.taker.to_account_info(),
+            &ctx.accounts.token_program_destination,
+            params.amount_destination,
+            None,
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    let expires_at = current_time
+        .checked_add(params.duration_seconds)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+
+    // Initialize offer
+    let offer = &mut ctx.accounts.offer;
+    offer.id = params.id;
+    offer.listing = listing.key();
+    offer.listing_id = listing.id;
+    offer.maker = listing.maker;
+    offer.taker = ctx.accounts.taker.key();
+    offer.token_mint_source = listing.token_mint_source;
+    offer.token_mint_destination = listing.token_mint_destination;
+    offer.amount_source = params.amount_source;
+    offer.amount_destination = amount_destination;
+    offer.expires_at = expires_at;
+    offer.created_at = current_time;
+    offer.bump = ctx.bumps.offer;
+
+    emit!(OfferMade {
+        offer: offer.key(),
+        offer_id: params.id,
+        listing_id: listing.id,
+        maker: listing.maker,
+        taker: ctx.accounts.taker.key(),
+        amount_source: params.amount_source,
+        amount_destination,
+        expires_at,
+        timestamp: current_time,
+    });
+
+    // Offer audit log
+    msg!("OFFER MADE");
+    msg!("------------------");
+    msg!("Offer ID: {}", params.id);
+    msg!("Listing ID: {}", listing.id);
+    msg!("Maker: {}", listing.maker);
+    msg!("Taker: {}", ctx.accounts.taker.key());
+    msg!("Amount Source: {}", params.amount_source);
+    msg!("Amount Destination: {}", amount_destination);
+    msg!("Expires At: {}", expires_at);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
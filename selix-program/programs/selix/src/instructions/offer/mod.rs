@@ -0,0 +1,11 @@
+#![allow(ambiguous_glob_reexports)]
+
+pub mod accept_offer;
+pub mod close_expired_offer;
+pub mod make_offer;
+pub mod withdraw_offer;
+
+pub use accept_offer::*;
+pub use close_expired_offer::*;
+pub use make_offer::*;
+pub use withdraw_offer::*;
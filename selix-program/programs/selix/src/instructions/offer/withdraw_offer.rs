@@ -0,0 +1,122 @@
+use crate::{constants::*, errors::SelixError, events::OfferWithdrawn, state::Offer, utils::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct WithdrawOffer<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    #[account(
+        mut,
+        close = taker,
+        seeds = [
+            OFFER_SEED,
+            offer.listing.as_ref(),
+            taker.key().as_ref(),
+            &offer.id.to_le_bytes(),
+        ],
+        bump = offer.bump,
+        has_one = taker @ SelixError::UnauthorizedAuthority,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub offer_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Optional: omitted when the destination is native SOL
+    #[account(
+        mut,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_token_account_destination: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Writable so withheld transfer fees can be harvested before the vault closes
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        constraint = token_mint_destination.key() == offer.token_mint_destination
+            @ SelixError::TokenAccountMintMismatch,
+    )]
+    pub token_mint_destination: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawOffer<'info>>) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let amount_to_return = ctx.accounts.offer_vault.amount;
+
+    let taker_key = ctx.accounts.taker.key();
+    let offer_seeds = &[
+        OFFER_SEED,
+        offer.listing.as_ref(),
+        taker_key.as_ref(),
+        &offer.id.to_le_bytes(),
+        &[offer.bump],
+    ];
+    let signer_seeds = &[&offer_seeds[..]];
+
+    // A wSOL vault unwraps straight to the taker when closed
+    if amount_to_return > 0 && !is_native_mint(&ctx.accounts.token_mint_destination.key()) {
+        let taker_token_account_destination = ctx
+            .accounts
+            .taker_token_account_destination
+            .as_ref()
+            .ok_or(SelixError::InvalidTokenAccount)?;
+
+        transfer_tokens(
+            &ctx.accounts.offer_vault,
+            taker_token_account_destination,
+            &ctx.accounts.token_mint_destination,
+            &offer.to_account_info(),
+            &ctx.accounts.token_program,
+            amount_to_return,
+            Some(signer_seeds),
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    // Close vault
+    harvest_withheld_fees(
+        &ctx.accounts.offer_vault,
+        &ctx.accounts.token_mint_destination,
+        &ctx.accounts.token_program,
+    )?;
+    close_token_account(
+        &ctx.accounts.offer_vault,
+        &ctx.accounts.taker.to_account_info(),
+        &offer.to_account_info(),
+        &ctx.accounts.token_program,
+        Some(signer_seeds),
+    )?;
+
+    emit!(OfferWithdrawn {
+        offer: offer.key(),
+        offer_id: offer.id,
+        listing_id: offer.listing_id,
+        taker: taker_key,
+        amount_returned: amount_to_return,
+        timestamp: current_time,
+    });
+
+    // Offer audit log
+    msg!("OFFER WITHDRAWN");
+    msg!("------------------");
+    msg!("Offer ID: {}", offer.id);
+    msg!("Listing ID: {}", offer.listing_id);
+    msg!("Taker: {}", taker_key);
+    msg!("Amount Returned: {}", amount_to_return);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
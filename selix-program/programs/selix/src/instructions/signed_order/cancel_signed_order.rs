@@ -0,0 +1,62 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::{SignedOrderCancelled, SignedOrderNonceFloorRaised},
+    state::OrderNonces,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelSignedOrder<'info> {
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ORDER_NONCES_SEED, maker.key().as_ref()],
+        bump = order_nonces.bump,
+        has_one = maker @ SelixError::UnauthorizedAuthority,
+    )]
+    pub order_nonces: Account<'info, OrderNonces>,
+}
+
+pub fn cancel_handler(ctx: Context<CancelSignedOrder>, nonce: u64) -> Result<()> {
+    let order_nonces = &mut ctx.accounts.order_nonces;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    order_nonces.use_nonce(nonce)?;
+
+    emit!(SignedOrderCancelled {
+        maker: order_nonces.maker,
+        nonce,
+        timestamp: current_time,
+    });
+
+    msg!("SIGNED ORDER CANCELLED");
+    msg!("------------------------");
+    msg!("Maker: {}", order_nonces.maker);
+    msg!("Nonce: {}", nonce);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
+
+pub fn cancel_below_handler(ctx: Context<CancelSignedOrder>, nonce_floor: u64) -> Result<()> {
+    let order_nonces = &mut ctx.accounts.order_nonces;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    order_nonces.raise_floor(nonce_floor)?;
+
+    emit!(SignedOrderNonceFloorRaised {
+        maker: order_nonces.maker,
+        nonce_floor,
+        timestamp: current_time,
+    });
+
+    msg!("SIGNED ORDERS CANCELLED");
+    msg!("-------------------------");
+    msg!("Maker: {}", order_nonces.maker);
+    msg!("Nonce Floor: {}", nonce_floor);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use super::fill_signed_order::{settle_signed_trade, FillSignedOrder, SignedTradeTerms};
+use crate::{constants::*, errors::SelixError, events::RfqFilled, state::ListingSide};
+use anchor_lang::prelude::*;
+
+/// Firm quote a maker signs for one taker's request; the maker signs
+/// `RFQ_QUOTE_DOMAIN` followed by the Borsh encoding of this struct
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RfqQuote {
+    /// Platform the quote settles on
+    pub platform: Pubkey,
+    pub maker: Pubkey,
+    /// Taker who requested the quote; nobody else can fill it
+    pub taker: Pubkey,
+    /// Single-use nonce tracked in the maker's `OrderNonces` account
+    pub nonce: u64,
+    /// Ask sells the source (base) token, Bid sells the source (quote) token
+    pub side: ListingSide,
+    pub token_mint_source: Pubkey,
+    pub token_mint_destination: Pubkey,
+    /// Filled in full
+    pub amount_source: u64,
+    pub amount_destination: u64,
+    /// Last slot the quote can be filled in
+    pub expires_at_slot: u64,
+}
+
+impl RfqQuote {
+    /// Message the maker signs
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = RFQ_QUOTE_DOMAIN.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillSignedOrder<'info>>,
+    quote: RfqQuote,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    // Validate the quote is live and short-lived
+    require!(
+        clock.slot <= quote.expires_at_slot,
+        SelixError::QuoteExpired
+    );
+    require!(
+        quote.expires_at_slot - clock.slot <= MAX_RFQ_QUOTE_SLOTS,
+        SelixError::QuoteValidityTooLong
+    );
+
+    // Quotes are firm for the requesting taker only
+    require_keys_eq!(
+        ctx.accounts.taker.key(),
+        quote.taker,
+        SelixError::TakerNotAllowed
+    );
+
+    let terms = SignedTradeTerms {
+        platform: quote.platform,
+        maker: quote.maker,
+        taker: quote.taker,
+        nonce: quote.nonce,
+        side: quote.side,
+        token_mint_source: quote.token_mint_source,
+        token_mint_destination: quote.token_mint_destination,
+        amount_source: quote.amount_source,
+        amount_destination: quote.amount_destination,
+    };
+    let fill = settle_signed_trade(
+        ctx.accounts,
+        ctx.bumps.order_authority,
+        ctx.program_id,
+        &terms,
+        &quote.message()?,
+        current_time,
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(RfqFilled {
+        maker: quote.maker,
+        taker: quote.taker,
+        nonce: quote.nonce,
+        token_mint_source: quote.token_mint_source,
+        token_mint_destination: quote.token_mint_destination,
+        side: quote.side,
+        amount_source: quote.amount_source,
+        amount_destination: quote.amount_destination,
+        quote_volume: fill.quote_volume,
+        fee_amount: fill.fee_amount,
+        source_transfer_fee: fill.source_transfer_fee,
+        destination_transfer_fee: fill.destination_transfer_fee,
+        expires_at_slot: quote.expires_at_slot,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "RFQ: {} nonce={} src={} dst={} fee={}",
+        quote.maker,
+        quote.nonce,
+        quote.amount_source,
+        quote.amount_destination,
+        fill.fee_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,422 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::SignedOrderFilled,
+    state::{ListingSide, OrderNonces, Platform, TokenWhitelist, UserProfile},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Maker order signed off-chain; the maker signs `SIGNED_ORDER_DOMAIN`
+/// followed by the Borsh encoding of this struct
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SignedOrder {
+    /// Platform the order settles on
+    pub platform: Pubkey,
+    pub maker: Pubkey,
+    /// Single-use nonce tracked in the maker's `OrderNonces` account
+    pub nonce: u64,
+    /// Ask sells the source (base) token, Bid sells the source (quote) token
+    pub side: ListingSide,
+    pub token_mint_source: Pubkey,
+    pub token_mint_destination: Pubkey,
+    /// Filled in full (fill-or-kill)
+    pub amount_source: u64,
+    pub amount_destination: u64,
+    /// Only this taker may fill (default pubkey for anyone)
+    pub taker: Pubkey,
+    pub expires_at: i64,
+}
+
+impl SignedOrder {
+    /// Message the maker signs
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = SIGNED_ORDER_DOMAIN.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+/// Terms shared by signed orders and RFQ quotes
+pub struct SignedTradeTerms {
+    pub platform: Pubkey,
+    pub maker: Pubkey,
+    /// Only this taker may fill (default pubkey for anyone)
+    pub taker: Pubkey,
+    pub nonce: u64,
+    pub side: ListingSide,
+    pub token_mint_source: Pubkey,
+    pub token_mint_destination: Pubkey,
+    pub amount_source: u64,
+    pub amount_destination: u64,
+}
+
+/// Amounts booked by a settled signed trade
+pub struct SignedTradeFill {
+    /// Quote token amount of the fill (destination for asks, source for bids)
+    pub quote_volume: u64,
+    pub fee_amount: u64,
+    /// Token-2022 transfer fee withheld from the source tokens sent to the taker
+    pub source_transfer_fee: u64,
+    /// Token-2022 transfer fees withheld from the maker and fee collector legs
+    pub destination_transfer_fee: u64,
+}
+
+/// Settlement of a maker-signed trade (signed orders and RFQ quotes)
+#[derive(Accounts)]
+pub struct FillSignedOrder<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, taker.key().as_ref()],
+        bump = taker_profile.bump,
+    )]
+    pub taker_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    /// CHECK: Maker who signed the order. Validated against the signed terms.
+    pub maker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    #[account(
+        mut,
+        seeds = [ORDER_NONCES_SEED, maker.key().as_ref()],
+        bump = order_nonces.bump,
+    )]
+    pub order_nonces: Box<Account<'info, OrderNonces>>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Box<Account<'info, Platform>>,
+
+    /// CHECK: Fee collector wallet. Validated against platform state.
+    #[account(
+        constraint = fee_collector.key() == platform.fee_collector
+            @ SelixError::UnauthorizedAuthority
+    )]
+    pub fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: Program PDA the maker approved as delegate on their source account
+    #[account(seeds = [ORDER_AUTHORITY_SEED], bump)]
+    pub order_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_source,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program_source,
+    )]
+    pub maker_token_account_source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program_destination,
+    )]
+    pub maker_token_account_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_source,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program_source,
+    )]
+    pub taker_token_account_source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program_destination,
+    )]
+    pub taker_token_account_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = fee_collector,
+        associated_token::token_program = token_program_destination,
+    )]
+    pub fee_collector_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program_source)]
+    pub token_mint_source: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program_destination)]
+    pub token_mint_destination: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Optional: required when platform.whitelist_enabled is true
+    pub source_whitelist: Option<Account<'info, TokenWhitelist>>,
+
+    /// Optional: required when platform.whitelist_enabled is true
+    pub dest_whitelist: Option<Account<'info, TokenWhitelist>>,
+
+    /// CHECK: Instructions sysvar, read for the Ed25519 signature instruction
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub token_program_source: Interface<'info, TokenInterface>,
+    pub token_program_destination: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillSignedOrder<'info>>,
+    order: SignedOrder,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate order is live
+    require!(current_time < order.expires_at, SelixError::OrderExpired);
+
+    let terms = SignedTradeTerms {
+        platform: order.platform,
+        maker: order.maker,
+        taker: order.taker,
+        nonce: order.nonce,
+        side: order.side,
+        token_mint_source: order.token_mint_source,
+        token_mint_destination: order.token_mint_destination,
+        amount_source: order.amount_source,
+        amount_destination: order.amount_destination,
+    };
+    let fill = settle_signed_trade(
+        ctx.accounts,
+        ctx.bumps.order_authority,
+        ctx.program_id,
+        &terms,
+        &order.message()?,
+        current_time,
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(SignedOrderFilled {
+        maker: order.maker,
+        taker: ctx.accounts.taker.key(),
+        nonce: order.nonce,
+        token_mint_source: order.token_mint_source,
+        token_mint_destination: order.token_mint_destination,
+        side: order.side,
+        amount_source: order.amount_source,
+        amount_destination: order.amount_destination,
+        quote_volume: fill.quote_volume,
+        fee_amount: fill.fee_amount,
+        source_transfer_fee: fill.source_transfer_fee,
+        destination_transfer_fee: fill.destination_transfer_fee,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "SIGNED ORDER: {} nonce={} src={} dst={} fee={}",
+        order.maker,
+        order.nonce,
+        order.amount_source,
+        order.amount_destination,
+        fill.fee_amount
+    );
+
+    Ok(())
+}
+
+/// Verify the maker's signature over `message`, burn the nonce and settle
+/// both legs through the maker's delegate approval
+pub fn settle_signed_trade<'info>(
+    accounts: &mut FillSignedOrder<'info>,
+    order_authority_bump: u8,
+    program_id: &Pubkey,
+    terms: &SignedTradeTerms,
+    message: &[u8],
+    current_time: i64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<SignedTradeFill> {
+    let platform = &accounts.platform;
+
+    // Validate platform not paused
+    validate_not_paused(platform)?;
+
+    // Validate the accounts match the signed terms
+    require_keys_eq!(
+        platform.key(),
+        terms.platform,
+        SelixError::InvalidSignedOrder
+    );
+    require_keys_eq!(
+        accounts.maker.key(),
+        terms.maker,
+        SelixError::InvalidSignedOrder
+    );
+    require_keys_eq!(
+        accounts.token_mint_source.key(),
+        terms.token_mint_source,
+        SelixError::InvalidSignedOrder
+    );
+    require_keys_eq!(
+        accounts.token_mint_destination.key(),
+        terms.token_mint_destination,
+        SelixError::InvalidSignedOrder
+    );
+
+    // Validate taker
+    require_keys_neq!(
+        accounts.taker.key(),
+        terms.maker,
+        SelixError::CannotSwapOwnListing
+    );
+    require!(
+        terms.taker == Pubkey::default() || terms.taker == accounts.taker.key(),
+        SelixError::TakerNotAllowed
+    );
+
+    // Validate amounts and mints
+    validate_amount(terms.amount_source, platform.min_trade_amount)?;
+    require!(terms.amount_destination > 0, SelixError::InvalidAmount);
+    validate_different_mints(&terms.token_mint_source, &terms.token_mint_destination)?;
+
+    // Validate whitelist if enabled
+    if platform.whitelist_enabled {
+        validate_whitelisted(
+            accounts.source_whitelist.as_ref(),
+            &terms.token_mint_source,
+            program_id,
+        )?;
+        validate_whitelisted(
+            accounts.dest_whitelist.as_ref(),
+            &terms.token_mint_destination,
+            program_id,
+        )?;
+    }
+
+    // Signed trades settle without a listing, so apply the mint policy here
+    validate_mint_features(
+        mint_features(&accounts.token_mint_source)?,
+        platform.rejected_mint_features,
+    )?;
+    validate_mint_features(
+        mint_features(&accounts.token_mint_destination)?,
+        platform.rejected_mint_features,
+    )?;
+
+    // Verify the maker's signature and burn the nonce
+    verify_ed25519_signature(&accounts.instructions_sysvar, &terms.maker, message)?;
+    accounts.order_nonces.use_nonce(terms.nonce)?;
+
+    // Validate the maker's delegate approval and balance
+    let maker_token_account_source = &accounts.maker_token_account_source;
+    require!(
+        maker_token_account_source.delegate == Some(accounts.order_authority.key()).into()
+            && maker_token_account_source.delegated_amount >= terms.amount_source,
+        SelixError::MissingDelegateApproval
+    );
+    require!(
+        maker_token_account_source.amount >= terms.amount_source,
+        SelixError::InsufficientMakerBalance
+    );
+    require!(
+        accounts.taker_token_account_destination.amount >= terms.amount_destination,
+        SelixError::InsufficientTakerBalance
+    );
+
+    // Calculate fee
+    let fee_amount = calculate_fee(terms.amount_destination, platform.fee_basis_points)?;
+    let amount_to_maker = terms
+        .amount_destination
+        .checked_sub(fee_amount)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+
+    // Token-2022 transfer fees withheld from each leg of the trade
+    let source_transfer_fee =
+        calculate_transfer_fee(&accounts.token_mint_source, terms.amount_source)?;
+    let maker_transfer_fee =
+        calculate_transfer_fee(&accounts.token_mint_destination, amount_to_maker)?;
+    let fee_collector_transfer_fee =
+        calculate_transfer_fee(&accounts.token_mint_destination, fee_amount)?;
+    let destination_transfer_fee = maker_transfer_fee
+        .checked_add(fee_collector_transfer_fee)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+
+    // Volume is accounted in the quote token for both sides
+    let quote_volume = match terms.side {
+        ListingSide::Ask => terms.amount_destination,
+        ListingSide::Bid => terms.amount_source,
+    };
+
+    let authority_seeds: &[&[u8]] = &[ORDER_AUTHORITY_SEED, &[order_authority_bump]];
+    let signer_seeds = &[authority_seeds];
+
+    // 1. Maker → Taker (source tokens through the delegate approval)
+    transfer_tokens(
+        maker_token_account_source,
+        &accounts.taker_token_account_source,
+        &accounts.token_mint_source,
+        &accounts.order_authority.to_account_info(),
+        &accounts.token_program_source,
+        terms.amount_source,
+        Some(signer_seeds),
+        remaining_accounts,
+    )?;
+
+    // 2. Taker → Maker (destination tokens minus fee)
+    transfer_tokens(
+        &accounts.taker_token_account_destination,
+        &accounts.maker_token_account_destination,
+        &accounts.token_mint_destination,
+        &accounts.taker.to_account_info(),
+        &accounts.token_program_destination,
+        amount_to_maker,
+        None,
+        remaining_accounts,
+    )?;
+
+    // 3. Taker → Fee collector
+    if fee_amount > 0 {
+        transfer_tokens(
+            &accounts.taker_token_account_destination,
+            &accounts.fee_collector_token_account,
+            &accounts.token_mint_destination,
+            &accounts.taker.to_account_info(),
+            &accounts.token_program_destination,
+            fee_amount,
+            None,
+            remaining_accounts,
+        )?;
+    }
+
+    // Update platform stats
+    accounts.platform.record_swap(quote_volume, fee_amount)?;
+
+    // Update taker profile
+    if let Some(taker_profile) = &mut accounts.taker_profile {
+        taker_profile.record_swap_as_taker(quote_volume, fee_amount, current_time)?;
+    }
+
+    // Update maker profile
+    if let Some(maker_profile) = &mut accounts.maker_profile {
+        maker_profile.record_swap_as_maker(quote_volume, current_time)?;
+    }
+
+    Ok(SignedTradeFill {
+        quote_volume,
+        fee_amount,
+        source_transfer_fee,
+        destination_transfer_fee,
+    })
+}
@@ -0,0 +1,37 @@
+use crate::{constants::*, state::OrderNonces};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeOrderNonces<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        init,
+        payer = maker,
+        space = 8 + OrderNonces::INIT_SPACE,
+        seeds = [ORDER_NONCES_SEED, maker.key().as_ref()],
+        bump
+    )]
+    pub order_nonces: Account<'info, OrderNonces>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeOrderNonces>) -> Result<()> {
+    let order_nonces = &mut ctx.accounts.order_nonces;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    order_nonces.maker = ctx.accounts.maker.key();
+    order_nonces.nonce_floor = 0;
+    order_nonces.nonce_bitmap = [0; ORDER_NONCE_BITMAP_WORDS];
+    order_nonces.created_at = current_time;
+    order_nonces.bump = ctx.bumps.order_nonces;
+
+    msg!("ORDER NONCES INITIALIZED");
+    msg!("--------------------------");
+    msg!("Maker: {}", order_nonces.maker);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+#![allow(ambiguous_glob_reexports)]
+
+pub mod cancel_signed_order;
+pub mod fill_rfq;
+pub mod fill_signed_order;
+pub mod initialize_order_nonces;
+
+pub use cancel_signed_order::*;
+pub use fill_rfq::*;
+pub use fill_signed_order::*;
+pub use initialize_order_nonces::*;
@@ -0,0 +1,442 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::{RouteExecuted, SwapExecuted},
+    state::{Listing, ListingKind, ListingStatus, Platform, UserProfile},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Accounts passed in `remaining_accounts` for every hop, in order:
+/// listing, vault, maker, maker profile (program ID when omitted),
+/// maker token account and fee collector token account (both in the token
+/// paid into the hop), taker token account, token mint and token program
+/// (all three for the token the hop delivers)
+pub const ROUTE_HOP_ACCOUNTS: usize = 9;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ExecuteRouteParams {
+    /// Tokens paid into the first hop
+    pub amount_in: u64,
+    /// Minimum tokens received from the last hop
+    pub min_amount_out: u64,
+    /// Number of hops in `remaining_accounts`
+    pub hops: u8,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteRoute<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, taker.key().as_ref()],
+        bump = taker_profile.bump,
+    )]
+    pub taker_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Box<Account<'info, Platform>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint_in,
+        token::authority = taker,
+        token::token_program = token_program_in,
+    )]
+    pub taker_token_account_in: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program_in)]
+    pub token_mint_in: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program_in: Interface<'info, TokenInterface>,
+}
+
+/// Validate a listing loaded from `remaining_accounts` can be filled by `taker`
+pub fn validate_remaining_listing(listing: &mut Listing, taker: &Pubkey, now: i64) -> Result<()> {
+    // Scheduled listings become fillable once their start time has passed
+    if listing.is_ready_to_activate(now, None) {
+        listing.status = ListingStatus::Active;
+    }
+
+    require!(
+        listing.status != ListingStatus::Pending,
+        SelixError::ListingNotStarted
+    );
+    require!(listing.status.can_be_traded(), SelixError::ListingNotActive);
+    require!(!listing.is_expired(now), SelixError::ListingExpired);
+    require_keys_neq!(*taker, listing.maker, SelixError::CannotSwapOwnListing);
+    require!(listing.is_taker_allowed(taker), SelixError::TakerNotAllowed);
+
+    // Oracle prices and collection destinations need accounts a route does not carry
+    require!(
+        listing.kind != ListingKind::OraclePegged && !listing.destination_is_collection,
+        SelixError::UnsupportedListingKind
+    );
+
+    Ok(())
+}
+
+/// Load a maker profile from `remaining_accounts` (the program ID stands in
+/// for an omitted profile)
+pub fn load_maker_profile<'info>(
+    account: &'info AccountInfo<'info>,
+) -> Result<Option<Account<'info, UserProfile>>> {
+    if account.key() == crate::ID {
+        return Ok(None);
+    }
+    Ok(Some(Account::try_from(account)?))
+}
+
+/// Accounts of a listing filled from `remaining_accounts`
+pub struct RemainingFill<'a, 'info> {
+    pub taker: &'a AccountInfo<'info>,
+    pub listing: &'a mut Account<'info, Listing>,
+    pub vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub maker: &'a AccountInfo<'info>,
+    /// Program ID when the maker profile is omitted
+    pub maker_profile: &'info AccountInfo<'info>,
+    pub maker_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub fee_collector_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub taker_token_account_source: &'a InterfaceAccount<'info, TokenAccount>,
+    pub taker_token_account_destination: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_mint_source: &'a InterfaceAccount<'info, Mint>,
+    pub token_mint_destination: &'a InterfaceAccount<'info, Mint>,
+    pub token_program_source: &'a Interface<'info, TokenInterface>,
+    pub token_program_destination: &'a Interface<'info, TokenInterface>,
+    pub hook_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Move tokens for a validated fill, update listing, platform and profile
+/// stats and emit `SwapExecuted`. Returns the platform fee and the transfer
+/// fee withheld from the source tokens delivered to the taker.
+pub fn settle_remaining_fill<'info>(
+    fill: RemainingFill<'_, 'info>,
+    platform: &mut Platform,
+    taker_profile: Option<&mut UserProfile>,
+    amount_source: u64,
+    amount_destination: u64,
+    current_time: i64,
+    program_id: &Pubkey,
+) -> Result<(u64, u64)> {
+    let listing = fill.listing;
+
+    // Validate the maker has not bumped their cancel epoch since listing
+    let mut maker_profile = load_maker_profile(fill.maker_profile)?;
+    listing.validate_cancel_epoch(maker_profile.as_deref())?;
+
+    let destination_consumed =
+        listing.destination_consumed_by_fill(amount_source, amount_destination)?;
+
+    // Volume is accounted in the quote token for both sides
+    let listing_side = listing.side;
+    let quote_volume = listing.quote_amount(amount_source, amount_destination);
+
+    // Calculate fee
+    let fee_amount = calculate_fee(amount_destination, platform.fee_basis_points)?;
+    let amount_to_maker = amount_destination
+        .checked_sub(fee_amount)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+
+    // Token-2022 transfer fees withheld from each leg of the fill
+    let source_transfer_fee = calculate_transfer_fee(fill.token_mint_source, amount_source)?;
+    let maker_transfer_fee = calculate_transfer_fee(fill.token_mint_destination, amount_to_maker)?;
+    let destination_transfer_fee = maker_transfer_fee
+        .checked_add(calculate_transfer_fee(
+            fill.token_mint_destination,
+            fee_amount,
+        )?)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+
+    // Build listing PDA signer seeds
+    let listing_maker = listing.maker;
+    let id_bytes = listing.id.to_le_bytes();
+    let listing_seeds: &[&[u8]] = &[
+        LISTING_SEED,
+        listing_maker.as_ref(),
+        &id_bytes,
+        &[listing.bump],
+    ];
+    let signer_seeds = &[listing_seeds];
+    let listing_account_info = listing.to_account_info();
+
+    // 1. Vault → Taker
+    transfer_tokens(
+        fill.vault,
+        fill.taker_token_account_source,
+        fill.token_mint_source,
+        &listing_account_info,
+        fill.token_program_source,
+        amount_source,
+        Some(signer_seeds),
+        fill.hook_accounts,
+    )?;
+
+    // 2. Taker → Maker (minus fee)
+    transfer_tokens(
+        fill.taker_token_account_destination,
+        fill.maker_token_account,
+        fill.token_mint_destination,
+        fill.taker,
+        fill.token_program_destination,
+        amount_to_maker,
+        None,
+        fill.hook_accounts,
+    )?;
+
+    // 3. Taker → Fee collector
+    if fee_amount > 0 {
+        transfer_tokens(
+            fill.taker_token_account_destination,
+            fill.fee_collector_token_account,
+            fill.token_mint_destination,
+            fill.taker,
+            fill.token_program_destination,
+            fee_amount,
+            None,
+            fill.hook_accounts,
+        )?;
+    }
+
+    // Update listing state
+    let is_partial = listing.apply_fill(amount_source, destination_consumed, current_time)?;
+    listing.exit(program_id)?;
+
+    // Update platform stats
+    platform.record_swap(quote_volume, fee_amount)?;
+
+    // Update taker profile
+    if let Some(taker_profile) = taker_profile {
+        taker_profile.record_swap_as_taker(quote_volume, fee_amount, current_time)?;
+    }
+
+    // Update maker profile
+    if let Some(maker_profile) = &mut maker_profile {
+        maker_profile.record_swap_as_maker(quote_volume, current_time)?;
+        if !is_partial {
+            maker_profile.record_listing_closed(listing.good_till_cancelled);
+        }
+        maker_profile.exit(program_id)?;
+    }
+
+    // Close vault if fully filled
+    if !is_partial {
+        harvest_withheld_fees(
+            fill.vault,
+            fill.token_mint_source,
+            fill.token_program_source,
+        )?;
+        close_token_account(
+            fill.vault,
+            fill.maker,
+            &listing_account_info,
+            fill.token_program_source,
+            Some(signer_seeds),
+        )?;
+    }
+
+    emit!(SwapExecuted {
+        listing_id: listing.id,
+        maker: listing.maker,
+        taker: fill.taker.key(),
+        token_mint_source: fill.token_mint_source.key(),
+        token_mint_destination: fill.token_mint_destination.key(),
+        side: listing_side,
+        amount_source,
+        amount_destination,
+        quote_volume,
+        clearing_rate: calculate_rate(amount_source, amount_destination)?,
+        fee_amount,
+        source_transfer_fee,
+        destination_transfer_fee,
+        is_partial,
+        remaining_source: listing.visible_amount_source(),
+        new_status: listing.status,
+        timestamp: current_time,
+    });
+
+    Ok((fee_amount, source_transfer_fee))
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteRoute<'info>>,
+    params: ExecuteRouteParams,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate platform not paused
+    validate_not_paused(&ctx.accounts.platform)?;
+
+    // Validate route shape
+    let hops = params.hops as usize;
+    require!(
+        (1..=MAX_ROUTE_HOPS).contains(&hops),
+        SelixError::InvalidRoute
+    );
+    require!(params.amount_in > 0, SelixError::InvalidAmount);
+
+    let hop_accounts_len = hops * ROUTE_HOP_ACCOUNTS;
+    require!(
+        ctx.remaining_accounts.len() >= hop_accounts_len,
+        SelixError::InvalidRoute
+    );
+    // Accounts after the hops are extra accounts for transfer-hook mints
+    let (hop_accounts, hook_accounts) = ctx.remaining_accounts.split_at(hop_accounts_len);
+
+    let taker_key = ctx.accounts.taker.key();
+    let fee_collector = ctx.accounts.platform.fee_collector;
+
+    // Token the taker pays into the current hop
+    let mut pay_account = (*ctx.accounts.taker_token_account_in).clone();
+    let mut pay_mint = (*ctx.accounts.token_mint_in).clone();
+    let mut pay_program = ctx.accounts.token_program_in.clone();
+    let mut amount_available = params.amount_in;
+    let mut amount_in = 0;
+    let mut amounts_unspent = Vec::with_capacity(hops);
+    let mut listings = Vec::with_capacity(hops);
+
+    for accounts in hop_accounts.chunks(ROUTE_HOP_ACCOUNTS) {
+        let mut listing: Account<'info, Listing> = Account::try_from(&accounts[0])?;
+        let vault: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[1])?;
+        let maker = &accounts[2];
+        let maker_profile = &accounts[3];
+        let maker_token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[4])?;
+        let fee_collector_token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[5])?;
+        let taker_token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[6])?;
+        let token_mint: InterfaceAccount<'info, Mint> = InterfaceAccount::try_from(&accounts[7])?;
+        let token_program: Interface<'info, TokenInterface> = Interface::try_from(&accounts[8])?;
+
+        // Validate the hop continues the route
+        let listing_key = listing.key();
+        require!(!listings.contains(&listing_key), SelixError::InvalidRoute);
+        require_keys_eq!(
+            listing.token_mint_destination,
+            pay_mint.key(),
+            SelixError::InvalidRoute
+        );
+        require_keys_eq!(
+            listing.token_mint_source,
+            token_mint.key(),
+            SelixError::TokenAccountMintMismatch
+        );
+        require_keys_eq!(
+            *token_mint.to_account_info().owner,
+            token_program.key(),
+            SelixError::InvalidTokenAccount
+        );
+        require_keys_eq!(
+            maker.key(),
+            listing.maker,
+            SelixError::UnauthorizedAuthority
+        );
+
+        // Validate hop token accounts
+        validate_token_account(&vault, &token_mint.key(), &listing_key)?;
+        validate_token_account(&maker_token_account, &pay_mint.key(), &listing.maker)?;
+        validate_token_account(
+            &fee_collector_token_account,
+            &pay_mint.key(),
+            &fee_collector,
+        )?;
+        validate_token_account(&taker_token_account, &token_mint.key(), &taker_key)?;
+
+        validate_remaining_listing(&mut listing, &taker_key, current_time)?;
+
+        // Spend what the previous hop delivered, up to the listing's largest fill
+        let (amount_source, amount_destination, amount_unspent) = calculate_budget_fill(
+            listing.amount_source_remaining,
+            listing.current_amount_destination_remaining(current_time)?,
+            listing.max_fill_amount(current_time)?,
+            amount_available,
+        )?;
+        listing.validate_fill_amount(amount_source, current_time)?;
+        require!(amount_destination > 0, SelixError::InvalidAmount);
+
+        // Check taker has sufficient balance (includes earlier hops' output)
+        pay_account.reload()?;
+        require!(
+            pay_account.amount >= amount_destination,
+            SelixError::InsufficientTakerBalance
+        );
+
+        let (_, source_transfer_fee) = settle_remaining_fill(
+            RemainingFill {
+                taker: &ctx.accounts.taker.to_account_info(),
+                listing: &mut listing,
+                vault: &vault,
+                maker,
+                maker_profile,
+                maker_token_account: &maker_token_account,
+                fee_collector_token_account: &fee_collector_token_account,
+                taker_token_account_source: &taker_token_account,
+                taker_token_account_destination: &pay_account,
+                token_mint_source: &token_mint,
+                token_mint_destination: &pay_mint,
+                token_program_source: &token_program,
+                token_program_destination: &pay_program,
+                hook_accounts,
+            },
+            &mut ctx.accounts.platform,
+            ctx.accounts
+                .taker_profile
+                .as_deref_mut()
+                .map(|profile| &mut **profile),
+            amount_source,
+            amount_destination,
+            current_time,
+            ctx.program_id,
+        )?;
+
+        if listings.is_empty() {
+            amount_in = amount_destination;
+        }
+        amounts_unspent.push(amount_unspent);
+        listings.push(listing_key);
+
+        // The next hop spends what this hop delivered
+        amount_available = amount_source
+            .checked_sub(source_transfer_fee)
+            .ok_or(SelixError::ArithmeticUnderflow)?;
+        pay_account = taker_token_account;
+        pay_mint = token_mint;
+        pay_program = token_program;
+    }
+
+    // Validate end-to-end slippage
+    let amount_out = amount_available;
+    require!(
+        amount_out >= params.min_amount_out,
+        SelixError::SlippageExceeded
+    );
+
+    emit!(RouteExecuted {
+        taker: taker_key,
+        token_mint_in: ctx.accounts.token_mint_in.key(),
+        token_mint_out: pay_mint.key(),
+        amount_in,
+        amount_out,
+        amounts_unspent: amounts_unspent.clone(),
+        listings,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "ROUTE: hops={} in={} out={} unspent={:?}",
+        hops,
+        amount_in,
+        amount_out,
+        amounts_unspent
+    );
+
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use crate::{
     constants::*,
     errors::SelixError,
-    events::SwapExecuted,
-    state::{Listing, ListingStatus, Platform, UserProfile},
+    events::{ListingTriggered, SwapExecuted},
+    state::{Listing, ListingKind, ListingStatus, Platform, UserProfile},
     utils::*,
 };
 use anchor_lang::prelude::*;
@@ -30,8 +30,12 @@ pub struct ExecuteSwap<'info> {
     #[account(mut)]
     pub maker: UncheckedAccount<'info>,
 
-    #[account(mut)]
-    pub maker_profile: Option<Account<'info, UserProfile>>,
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Option<Box<Account<'info, UserProfile>>>,
 
     #[account(
         mut,
@@ -42,6 +46,7 @@ pub struct ExecuteSwap<'info> {
 
     /// CHECK: Fee collector wallet. Validated against platform state.
     #[account(
+        mut,
         constraint = fee_collector.key() == platform.fee_collector
             @ SelixError::UnauthorizedAuthority
     )]
@@ -59,7 +64,7 @@ pub struct ExecuteSwap<'info> {
         mut,
         associated_token::mint = token_mint_source,
         associated_token::authority = listing,
-        associated_token::token_program = token_program,
+        associated_token::token_program = token_program_source,
     )]
     pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -68,78 +73,166 @@ pub struct ExecuteSwap<'info> {
         payer = taker,
         associated_token::mint = token_mint_source,
         associated_token::authority = taker,
-        associated_token::token_program = token_program,
+        associated_token::token_program = token_program_source,
     )]
     pub taker_token_account_source: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Optional: omitted when the destination is native SOL
     #[account(
         mut,
         associated_token::mint = token_mint_destination,
         associated_token::authority = taker,
-        associated_token::token_program = token_program,
+        associated_token::token_program = token_program_destination,
     )]
-    pub taker_token_account_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub taker_token_account_destination: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
+    /// Optional: omitted when the destination is native SOL
     #[account(
-        mut,
+        init_if_needed,
+        payer = taker,
         associated_token::mint = token_mint_destination,
         associated_token::authority = maker,
-        associated_token::token_program = token_program,
+        associated_token::token_program = token_program_destination,
     )]
-    pub maker_token_account_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub maker_token_account_destination: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
+    /// Optional: omitted when the destination is native SOL
     #[account(
         init_if_needed,
         payer = taker,
         associated_token::mint = token_mint_destination,
         associated_token::authority = fee_collector,
-        associated_token::token_program = token_program,
+        associated_token::token_program = token_program_destination,
     )]
-    pub fee_collector_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub fee_collector_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
+    /// Writable so withheld transfer fees can be harvested before the vault closes
     #[account(
-        mint::token_program = token_program,
+        mut,
+        mint::token_program = token_program_source,
         constraint = token_mint_source.key() == listing.token_mint_source
             @ SelixError::TokenAccountMintMismatch,
     )]
     pub token_mint_source: Box<InterfaceAccount<'info, Mint>>,
 
+    /// Any NFT of the collection for collection listings
     #[account(
-        mint::token_program = token_program,
+        mint::token_program = token_program_destination,
         constraint = token_mint_destination.key() == listing.token_mint_destination
+            || listing.destination_is_collection
             @ SelixError::TokenAccountMintMismatch,
     )]
     pub token_mint_destination: Box<InterfaceAccount<'info, Mint>>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: Optional: Metaplex metadata of the delivered NFT, required for
+    /// collection listings. Validated in the handler.
+    pub destination_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional: required for oracle-pegged listings.
+    /// Validated against the listing and parsed in the handler.
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    pub token_program_source: Interface<'info, TokenInterface>,
+    pub token_program_destination: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<ExecuteSwap>, params: ExecuteSwapParams) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteSwap<'info>>,
+    params: ExecuteSwapParams,
+) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
 
     // Validate platform not paused
     require!(!ctx.accounts.platform.is_paused, SelixError::PlatformPaused);
 
+    // Price from the feed, rejecting stale or imprecise readings
+    let oracle_price = if ctx.accounts.listing.needs_price_feed() {
+        let price_feed = ctx
+            .accounts
+            .price_feed
+            .as_ref()
+            .ok_or(SelixError::InvalidPriceFeed)?;
+        require_keys_eq!(
+            price_feed.key(),
+            ctx.accounts.listing.price_feed,
+            SelixError::InvalidPriceFeed
+        );
+        Some(load_oracle_price(
+            price_feed,
+            Some(&ctx.accounts.listing.price_feed_id),
+            current_time,
+        )?)
+    } else {
+        None
+    };
+
+    // Scheduled listings become fillable once their start time has passed,
+    // and stop-loss listings once the oracle price crosses the trigger
+    if ctx
+        .accounts
+        .listing
+        .is_ready_to_activate(current_time, oracle_price.as_ref())
+    {
+        ctx.accounts.listing.status = ListingStatus::Active;
+
+        if ctx.accounts.listing.has_trigger() {
+            let price = oracle_price.as_ref().ok_or(SelixError::InvalidPriceFeed)?;
+            emit!(ListingTriggered {
+                listing_id: ctx.accounts.listing.id,
+                maker: ctx.accounts.listing.maker,
+                triggered_by: ctx.accounts.taker.key(),
+                oracle_price: price.price,
+                trigger_price: ctx.accounts.listing.trigger_price,
+                timestamp: current_time,
+            });
+        }
+    }
+
     // Snapshot listing data before mutable borrow
     let listing_id = ctx.accounts.listing.id;
     let listing_bump = ctx.accounts.listing.bump;
     let listing_status = ctx.accounts.listing.status;
-    let listing_expires_at = ctx.accounts.listing.expires_at;
+    let listing_is_expired = ctx.accounts.listing.is_expired(current_time);
+    let listing_good_till_cancelled = ctx.accounts.listing.good_till_cancelled;
     let listing_amount_source_remaining = ctx.accounts.listing.amount_source_remaining;
-    let listing_amount_destination_remaining = ctx.accounts.listing.amount_destination_remaining;
-    let listing_min_fill_amount = ctx.accounts.listing.min_fill_amount;
+    let listing_amount_destination_remaining =
+        if ctx.accounts.listing.kind == ListingKind::OraclePegged {
+            let price = oracle_price.as_ref().ok_or(SelixError::InvalidPriceFeed)?;
+
+            ctx.accounts.listing.oracle_amount_destination_remaining(
+                price,
+                ctx.accounts.token_mint_source.decimals,
+                ctx.accounts.token_mint_destination.decimals,
+            )?
+        } else {
+            ctx.accounts
+                .listing
+                .current_amount_destination_remaining(current_time)?
+        };
     let fee_basis_points = ctx.accounts.platform.fee_basis_points;
 
     // Validate listing is active
+    require!(
+        listing_status != ListingStatus::Pending,
+        SelixError::ListingNotStarted
+    );
     require!(
         listing_status == ListingStatus::Active || listing_status == ListingStatus::PartiallyFilled,
         SelixError::ListingNotActive
     );
 
     // Validate not expired
-    require!(!is_expired(listing_expires_at)?, SelixError::ListingExpired);
+    require!(!listing_is_expired, SelixError::ListingExpired);
+
+    // Validate the maker has not bumped their cancel epoch since listing
+    ctx.accounts.listing.validate_cancel_epoch(
+        ctx.accounts
+            .maker_profile
+            .as_deref()
+            .map(|profile| &**profile),
+    )?;
 
     // Validate taker is not maker
     require_keys_neq!(
@@ -148,17 +241,45 @@ pub fn handler(ctx: Context<ExecuteSwap>, params: ExecuteSwapParams) -> Result<(
         SelixError::CannotSwapOwnListing
     );
 
-    // Validate swap amount
+    // Validate taker is allowed on private listings
     require!(
-        params.amount_source <= listing_amount_source_remaining,
-        SelixError::SwapAmountExceedsRemaining
-    );
-    require!(
-        params.amount_source >= listing_min_fill_amount,
-        SelixError::FillAmountTooSmall
+        ctx.accounts
+            .listing
+            .is_taker_allowed(&ctx.accounts.taker.key()),
+        SelixError::TakerNotAllowed
     );
 
-    // Calculate proportional destination amount
+    // Collection listings accept any verified NFT of the collection
+    if ctx.accounts.listing.destination_is_collection {
+        require!(
+            is_nft(
+                ctx.accounts.token_mint_destination.decimals,
+                ctx.accounts.token_mint_destination.supply,
+            ),
+            SelixError::NotAnNft
+        );
+        validate_collection_nft(
+            ctx.accounts
+                .destination_metadata
+                .as_ref()
+                .ok_or(SelixError::InvalidNftMetadata)?,
+            &ctx.accounts.token_mint_destination.key(),
+            &ctx.accounts.listing.token_mint_destination,
+        )?;
+
+        // The NFT is picked at fill time, so apply the mint policy here
+        validate_mint_features(
+            mint_features(&ctx.accounts.token_mint_destination)?,
+            ctx.accounts.platform.rejected_mint_features,
+        )?;
+    }
+
+    // Validate swap amount
+    ctx.accounts
+        .listing
+        .validate_fill_amount(params.amount_source, current_time)?;
+
+    // Calculate proportional destination amount at the current price
     let (amount_source, amount_destination) = calculate_partial_amounts(
         listing_amount_source_remaining,
         listing_amount_destination_remaining,
@@ -171,15 +292,50 @@ pub fn handler(ctx: Context<ExecuteSwap>, params: ExecuteSwapParams) -> Result<(
         SelixError::SlippageExceeded
     );
 
+    let destination_consumed = ctx
+        .accounts
+        .listing
+        .destination_consumed_by_fill(amount_source, amount_destination)?;
+
+    // Volume is accounted in the quote token for both sides
+    let listing_side = ctx.accounts.listing.side;
+    let quote_volume = ctx
+        .accounts
+        .listing
+        .quote_amount(amount_source, amount_destination);
+
     // Calculate fee
     let fee_amount = calculate_fee(amount_destination, fee_basis_points)?;
     let amount_to_maker = amount_destination
         .checked_sub(fee_amount)
         .ok_or(SelixError::ArithmeticUnderflow)?;
 
+    // Token-2022 transfer fees withheld from each leg of the swap
+    let source_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.token_mint_source, amount_source)?;
+    let maker_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.token_mint_destination, amount_to_maker)?;
+    let fee_collector_transfer_fee =
+        calculate_transfer_fee(&ctx.accounts.token_mint_destination, fee_amount)?;
+    let destination_transfer_fee = maker_transfer_fee
+        .checked_add(fee_collector_transfer_fee)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+
+    let source_is_native = is_native_mint(&ctx.accounts.token_mint_source.key());
+    let destination_is_native = is_native_mint(&ctx.accounts.token_mint_destination.key());
+
     // Check taker has sufficient balance
+    let taker_destination_balance = if destination_is_native {
+        ctx.accounts.taker.lamports()
+    } else {
+        ctx.accounts
+            .taker_token_account_destination
+            .as_ref()
+            .ok_or(SelixError::InvalidTokenAccount)?
+            .amount
+    };
     require!(
-        ctx.accounts.taker_token_account_destination.amount >= amount_destination,
+        taker_destination_balance >= amount_destination,
         SelixError::InsufficientTakerBalance
     );
 
@@ -192,120 +348,128 @@ pub fn handler(ctx: Context<ExecuteSwap>, params: ExecuteSwapParams) -> Result<(
     // Grab AccountInfo before mutable borrow of listing
     let listing_account_info = ctx.accounts.listing.to_account_info();
 
+    // wSOL the taker already held must stay wrapped, so only an empty
+    // (freshly created) account is closed to unwrap the fill
+    let unwrap_source = source_is_native && ctx.accounts.taker_token_account_source.amount == 0;
+
     // 1. Vault → Taker (source tokens)
     transfer_tokens(
         &ctx.accounts.vault,
         &ctx.accounts.taker_token_account_source,
         &ctx.accounts.token_mint_source,
         &listing_account_info,
-        &ctx.accounts.token_program,
+        &ctx.accounts.token_program_source,
         amount_source,
         Some(signer_seeds),
+        ctx.remaining_accounts,
     )?;
 
-    // 2. Taker → Maker (destination tokens minus fee)
-    transfer_tokens(
-        &ctx.accounts.taker_token_account_destination,
-        &ctx.accounts.maker_token_account_destination,
-        &ctx.accounts.token_mint_destination,
-        &ctx.accounts.taker.to_account_info(),
-        &ctx.accounts.token_program,
-        amount_to_maker,
-        None,
-    )?;
+    // Unwrap native SOL by closing the taker's temporary wSOL account
+    if unwrap_source {
+        close_token_account(
+            &ctx.accounts.taker_token_account_source,
+            &ctx.accounts.taker.to_account_info(),
+            &ctx.accounts.taker.to_account_info(),
+            &ctx.accounts.token_program_source,
+            None,
+        )?;
+    }
+
+    // Native SOL destinations move wallet lamports directly, so neither side
+    // needs a wSOL account for this leg
+    if destination_is_native {
+        // 2. Taker → Maker (native SOL minus fee)
+        transfer_lamports(
+            &ctx.accounts.taker.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.system_program,
+            amount_to_maker,
+        )?;
 
-    // 3. Taker → Fee collector
-    if fee_amount > 0 {
+        // 3. Taker → Fee collector
+        if fee_amount > 0 {
+            transfer_lamports(
+                &ctx.accounts.taker.to_account_info(),
+                &ctx.accounts.fee_collector.to_account_info(),
+                &ctx.accounts.system_program,
+                fee_amount,
+            )?;
+        }
+    } else {
+        let taker_token_account_destination = ctx
+            .accounts
+            .taker_token_account_destination
+            .as_ref()
+            .ok_or(SelixError::InvalidTokenAccount)?;
+
+        // 2. Taker → Maker (destination tokens minus fee)
         transfer_tokens(
-            &ctx.accounts.taker_token_account_destination,
-            &ctx.accounts.fee_collector_token_account,
+            taker_token_account_destination,
+            ctx.accounts
+                .maker_token_account_destination
+                .as_ref()
+                .ok_or(SelixError::InvalidTokenAccount)?,
             &ctx.accounts.token_mint_destination,
             &ctx.accounts.taker.to_account_info(),
-            &ctx.accounts.token_program,
-            fee_amount,
+            &ctx.accounts.token_program_destination,
+            amount_to_maker,
             None,
+            ctx.remaining_accounts,
         )?;
+
+        // 3. Taker → Fee collector
+        if fee_amount > 0 {
+            transfer_tokens(
+                taker_token_account_destination,
+                ctx.accounts
+                    .fee_collector_token_account
+                    .as_ref()
+                    .ok_or(SelixError::InvalidTokenAccount)?,
+                &ctx.accounts.token_mint_destination,
+                &ctx.accounts.taker.to_account_info(),
+                &ctx.accounts.token_program_destination,
+                fee_amount,
+                None,
+                ctx.remaining_accounts,
+            )?;
+        }
     }
 
     // Update listing state
     let listing = &mut ctx.accounts.listing;
-    listing.amount_source_remaining = listing
-        .amount_source_remaining
-        .checked_sub(amount_source)
-        .ok_or(SelixError::ArithmeticUnderflow)?;
-    listing.amount_destination_remaining = listing
-        .amount_destination_remaining
-        .checked_sub(amount_destination)
-        .ok_or(SelixError::ArithmeticUnderflow)?;
-    listing.fill_count = listing
-        .fill_count
-        .checked_add(1)
-        .ok_or(SelixError::ArithmeticOverflow)?;
-    listing.updated_at = current_time;
-
-    let is_partial = listing.amount_source_remaining > 0;
-    listing.status = if is_partial {
-        ListingStatus::PartiallyFilled
-    } else {
-        ListingStatus::Completed
-    };
+    let is_partial = listing.apply_fill(amount_source, destination_consumed, current_time)?;
 
     // Update platform stats
-    let platform = &mut ctx.accounts.platform;
-    platform.total_swaps_executed = platform
-        .total_swaps_executed
-        .checked_add(1)
-        .ok_or(SelixError::ArithmeticOverflow)?;
-    platform.total_volume_traded = platform
-        .total_volume_traded
-        .checked_add(amount_destination as u128)
-        .ok_or(SelixError::ArithmeticOverflow)?;
-    platform.total_fees_collected = platform
-        .total_fees_collected
-        .checked_add(fee_amount)
-        .ok_or(SelixError::ArithmeticOverflow)?;
+    ctx.accounts
+        .platform
+        .record_swap(quote_volume, fee_amount)?;
 
     // Update taker profile
     if let Some(taker_profile) = &mut ctx.accounts.taker_profile {
-        taker_profile.swaps_executed = taker_profile
-            .swaps_executed
-            .checked_add(1)
-            .ok_or(SelixError::ArithmeticOverflow)?;
-        taker_profile.volume_as_taker = taker_profile
-            .volume_as_taker
-            .checked_add(amount_destination as u128)
-            .ok_or(SelixError::ArithmeticOverflow)?;
-        taker_profile.total_fees_paid = taker_profile
-            .total_fees_paid
-            .checked_add(fee_amount)
-            .ok_or(SelixError::ArithmeticOverflow)?;
-        taker_profile.last_activity_at = current_time;
+        taker_profile.record_swap_as_taker(quote_volume, fee_amount, current_time)?;
     }
 
     // Update maker profile
     if let Some(maker_profile) = &mut ctx.accounts.maker_profile {
-        maker_profile.swaps_received = maker_profile
-            .swaps_received
-            .checked_add(1)
-            .ok_or(SelixError::ArithmeticOverflow)?;
-        maker_profile.volume_as_maker = maker_profile
-            .volume_as_maker
-            .checked_add(amount_to_maker as u128)
-            .ok_or(SelixError::ArithmeticOverflow)?;
-        maker_profile.last_activity_at = current_time;
+        maker_profile.record_swap_as_maker(quote_volume, current_time)?;
 
         if !is_partial {
-            maker_profile.active_listings = maker_profile.active_listings.saturating_sub(1);
+            maker_profile.record_listing_closed(listing_good_till_cancelled);
         }
     }
 
     // Close vault if fully filled
     if !is_partial {
+        harvest_withheld_fees(
+            &ctx.accounts.vault,
+            &ctx.accounts.token_mint_source,
+            &ctx.accounts.token_program_source,
+        )?;
         close_token_account(
             &ctx.accounts.vault,
             &ctx.accounts.maker.to_account_info(),
             &listing_account_info,
-            &ctx.accounts.token_program,
+            &ctx.accounts.token_program_source,
             Some(signer_seeds),
         )?;
     }
@@ -316,11 +480,16 @@ pub fn handler(ctx: Context<ExecuteSwap>, params: ExecuteSwapParams) -> Result<(
         taker: ctx.accounts.taker.key(),
         token_mint_source: ctx.accounts.token_mint_source.key(),
         token_mint_destination: ctx.accounts.token_mint_destination.key(),
+        side: listing_side,
         amount_source,
         amount_destination,
+        quote_volume,
+        clearing_rate: calculate_rate(amount_source, amount_destination)?,
         fee_amount,
+        source_transfer_fee,
+        destination_transfer_fee,
         is_partial,
-        remaining_source: listing.amount_source_remaining,
+        remaining_source: listing.visible_amount_source(),
         new_status: listing.status,
         timestamp: current_time,
     });
@@ -0,0 +1,458 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::ListingsMatched,
+    state::{Listing, ListingKind, ListingSide, ListingStatus, Platform, UserProfile},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct MatchListings<'info> {
+    /// Anyone can match crossing listings; pays for missing token accounts
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Box<Account<'info, Platform>>,
+
+    /// CHECK: Fee collector wallet. Validated against platform state.
+    #[account(
+        constraint = fee_collector.key() == platform.fee_collector
+            @ SelixError::UnauthorizedAuthority
+    )]
+    pub fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: Maker of listing A; receives token B and vault rent.
+    /// Validated against listing_a.maker.
+    #[account(mut)]
+    pub maker_a: UncheckedAccount<'info>,
+
+    /// CHECK: Maker of listing B; receives token A and vault rent.
+    /// Validated against listing_b.maker.
+    #[account(mut)]
+    pub maker_b: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, maker_a.key().as_ref()],
+        bump = maker_a_profile.bump,
+    )]
+    pub maker_a_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, maker_b.key().as_ref()],
+        bump = maker_b_profile.bump,
+    )]
+    pub maker_b_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    /// Sells token A for token B
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, maker_a.key().as_ref(), &listing_a.id.to_le_bytes()],
+        bump = listing_a.bump,
+        constraint = listing_a.maker == maker_a.key() @ SelixError::UnauthorizedAuthority,
+    )]
+    pub listing_a: Box<Account<'info, Listing>>,
+
+    /// Sells token B for token A
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, maker_b.key().as_ref(), &listing_b.id.to_le_bytes()],
+        bump = listing_b.bump,
+        constraint = listing_b.maker == maker_b.key() @ SelixError::UnauthorizedAuthority,
+    )]
+    pub listing_b: Box<Account<'info, Listing>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = listing_a,
+        associated_token::token_program = token_program_a,
+    )]
+    pub vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = listing_b,
+        associated_token::token_program = token_program_b,
+    )]
+    pub vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Maker A's proceeds in token B
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = maker_a,
+        associated_token::token_program = token_program_b,
+    )]
+    pub maker_a_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Maker B's proceeds in token A
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = maker_b,
+        associated_token::token_program = token_program_a,
+    )]
+    pub maker_b_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = fee_collector,
+        associated_token::token_program = token_program_a,
+    )]
+    pub fee_collector_token_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = fee_collector,
+        associated_token::token_program = token_program_b,
+    )]
+    pub fee_collector_token_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Writable so withheld transfer fees can be harvested before the vault closes
+    #[account(
+        mut,
+        mint::token_program = token_program_a,
+        constraint = token_mint_a.key() == listing_a.token_mint_source
+            @ SelixError::TokenAccountMintMismatch,
+    )]
+    pub token_mint_a: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Writable so withheld transfer fees can be harvested before the vault closes
+    #[account(
+        mut,
+        mint::token_program = token_program_b,
+        constraint = token_mint_b.key() == listing_b.token_mint_source
+            @ SelixError::TokenAccountMintMismatch,
+    )]
+    pub token_mint_b: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program_a: Interface<'info, TokenInterface>,
+    pub token_program_b: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Validate a listing can be matched and return its current remaining amounts
+fn matchable_amounts(
+    listing: &mut Listing,
+    maker_profile: Option<&UserProfile>,
+    counterparty: &Pubkey,
+    now: i64,
+) -> Result<(u64, u64)> {
+    // Scheduled listings become fillable once their start time has passed
+    if listing.is_ready_to_activate(now, None) {
+        listing.status = ListingStatus::Active;
+    }
+
+    require!(
+        listing.status != ListingStatus::Pending,
+        SelixError::ListingNotStarted
+    );
+    require!(listing.status.can_be_traded(), SelixError::ListingNotActive);
+    require!(!listing.is_expired(now), SelixError::ListingExpired);
+    listing.validate_cancel_epoch(maker_profile)?;
+
+    // Oracle prices and collection destinations need accounts a match does not carry
+    require!(
+        listing.kind != ListingKind::OraclePegged && !listing.destination_is_collection,
+        SelixError::UnsupportedListingKind
+    );
+
+    require!(
+        listing.is_taker_allowed(counterparty),
+        SelixError::TakerNotAllowed
+    );
+
+    Ok((
+        listing.amount_source_remaining,
+        listing.current_amount_destination_remaining(now)?,
+    ))
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, MatchListings<'info>>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate platform not paused
+    validate_not_paused(&ctx.accounts.platform)?;
+
+    let maker_a_key = ctx.accounts.maker_a.key();
+    let maker_b_key = ctx.accounts.maker_b.key();
+
+    // A maker cannot trade against themselves
+    require_keys_neq!(maker_a_key, maker_b_key, SelixError::CannotSwapOwnListing);
+
+    // Validate the listings trade opposite sides of the same pair
+    require_keys_eq!(
+        ctx.accounts.listing_a.token_mint_destination,
+        ctx.accounts.listing_b.token_mint_source,
+        SelixError::ListingPairMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.listing_b.token_mint_destination,
+        ctx.accounts.listing_a.token_mint_source,
+        SelixError::ListingPairMismatch
+    );
+
+    let (a_source_remaining, a_destination_remaining) = matchable_amounts(
+        &mut ctx.accounts.listing_a,
+        ctx.accounts
+            .maker_a_profile
+            .as_deref()
+            .map(|profile| &**profile),
+        &maker_b_key,
+        current_time,
+    )?;
+    let (b_source_remaining, b_destination_remaining) = matchable_amounts(
+        &mut ctx.accounts.listing_b,
+        ctx.accounts
+            .maker_b_profile
+            .as_deref()
+            .map(|profile| &**profile),
+        &maker_a_key,
+        current_time,
+    )?;
+
+    // Fill as much as both listings allow, each at its maker's own price
+    let (amount_a, amount_b, amount_b_to_maker_a) = calculate_match_amounts(
+        a_source_remaining,
+        a_destination_remaining,
+        ctx.accounts.listing_a.max_fill_amount(current_time)?,
+        b_source_remaining,
+        b_destination_remaining,
+        ctx.accounts.listing_b.max_fill_amount(current_time)?,
+    )?;
+    ctx.accounts
+        .listing_a
+        .validate_fill_amount(amount_a, current_time)?;
+    ctx.accounts
+        .listing_b
+        .validate_fill_amount(amount_b, current_time)?;
+
+    let destination_consumed_a = ctx
+        .accounts
+        .listing_a
+        .destination_consumed_by_fill(amount_a, amount_b_to_maker_a)?;
+    let destination_consumed_b = ctx
+        .accounts
+        .listing_b
+        .destination_consumed_by_fill(amount_b, amount_a)?;
+
+    // The gap between the two prices goes to the platform
+    let surplus = amount_b
+        .checked_sub(amount_b_to_maker_a)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+
+    // Each maker pays the platform fee on their own proceeds
+    let fee_basis_points = ctx.accounts.platform.fee_basis_points;
+    let fee_a = calculate_fee(amount_b_to_maker_a, fee_basis_points)?;
+    let fee_b = calculate_fee(amount_a, fee_basis_points)?;
+    let amount_to_maker_a = amount_b_to_maker_a
+        .checked_sub(fee_a)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+    let amount_to_maker_b = amount_a
+        .checked_sub(fee_b)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+    let platform_amount_b = fee_a
+        .checked_add(surplus)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+
+    // Volume is accounted in each listing's quote token
+    let quote_volume_a = ctx
+        .accounts
+        .listing_a
+        .quote_amount(amount_a, amount_b_to_maker_a);
+    let quote_volume_b = ctx.accounts.listing_b.quote_amount(amount_b, amount_a);
+
+    // Build listing PDA signer seeds
+    let listing_a_id = ctx.accounts.listing_a.id;
+    let listing_a_id_bytes = listing_a_id.to_le_bytes();
+    let listing_a_seeds: &[&[u8]] = &[
+        LISTING_SEED,
+        maker_a_key.as_ref(),
+        &listing_a_id_bytes,
+        &[ctx.accounts.listing_a.bump],
+    ];
+    let listing_a_signer_seeds = &[listing_a_seeds];
+
+    let listing_b_id = ctx.accounts.listing_b.id;
+    let listing_b_id_bytes = listing_b_id.to_le_bytes();
+    let listing_b_seeds: &[&[u8]] = &[
+        LISTING_SEED,
+        maker_b_key.as_ref(),
+        &listing_b_id_bytes,
+        &[ctx.accounts.listing_b.bump],
+    ];
+    let listing_b_signer_seeds = &[listing_b_seeds];
+
+    let listing_a_account_info = ctx.accounts.listing_a.to_account_info();
+    let listing_b_account_info = ctx.accounts.listing_b.to_account_info();
+
+    // 1. Vault A → Maker B (token A minus fee)
+    transfer_tokens(
+        &ctx.accounts.vault_a,
+        &ctx.accounts.maker_b_token_account,
+        &ctx.accounts.token_mint_a,
+        &listing_a_account_info,
+        &ctx.accounts.token_program_a,
+        amount_to_maker_b,
+        Some(listing_a_signer_seeds),
+        ctx.remaining_accounts,
+    )?;
+
+    // 2. Vault A → Fee collector
+    if fee_b > 0 {
+        transfer_tokens(
+            &ctx.accounts.vault_a,
+            &ctx.accounts.fee_collector_token_account_a,
+            &ctx.accounts.token_mint_a,
+            &listing_a_account_info,
+            &ctx.accounts.token_program_a,
+            fee_b,
+            Some(listing_a_signer_seeds),
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    // 3. Vault B → Maker A (token B minus fee)
+    transfer_tokens(
+        &ctx.accounts.vault_b,
+        &ctx.accounts.maker_a_token_account,
+        &ctx.accounts.token_mint_b,
+        &listing_b_account_info,
+        &ctx.accounts.token_program_b,
+        amount_to_maker_a,
+        Some(listing_b_signer_seeds),
+        ctx.remaining_accounts,
+    )?;
+
+    // 4. Vault B → Fee collector (fee plus price surplus)
+    if platform_amount_b > 0 {
+        transfer_tokens(
+            &ctx.accounts.vault_b,
+            &ctx.accounts.fee_collector_token_account_b,
+            &ctx.accounts.token_mint_b,
+            &listing_b_account_info,
+            &ctx.accounts.token_program_b,
+            platform_amount_b,
+            Some(listing_b_signer_seeds),
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    // Update listing state
+    let listing_a = &mut ctx.accounts.listing_a;
+    let is_partial_a = listing_a.apply_fill(amount_a, destination_consumed_a, current_time)?;
+    let listing_a_good_till_cancelled = listing_a.good_till_cancelled;
+    let listing_a_status = listing_a.status;
+
+    let listing_b = &mut ctx.accounts.listing_b;
+    let is_partial_b = listing_b.apply_fill(amount_b, destination_consumed_b, current_time)?;
+    let listing_b_good_till_cancelled = listing_b.good_till_cancelled;
+    let listing_b_status = listing_b.status;
+
+    // Update platform stats (the match is a single trade, volume counted once).
+    // Volume is in listing A's quote token, so only the fee paid in that
+    // token is recorded: fee A is paid in token B and fee B in token A
+    let quote_fee = match ctx.accounts.listing_a.side {
+        ListingSide::Ask => fee_a,
+        ListingSide::Bid => fee_b,
+    };
+    ctx.accounts
+        .platform
+        .record_swap(quote_volume_a, quote_fee)?;
+
+    // Update maker profiles
+    if let Some(maker_a_profile) = &mut ctx.accounts.maker_a_profile {
+        maker_a_profile.record_swap_as_maker(quote_volume_a, current_time)?;
+
+        if !is_partial_a {
+            maker_a_profile.record_listing_closed(listing_a_good_till_cancelled);
+        }
+    }
+    if let Some(maker_b_profile) = &mut ctx.accounts.maker_b_profile {
+        maker_b_profile.record_swap_as_maker(quote_volume_b, current_time)?;
+
+        if !is_partial_b {
+            maker_b_profile.record_listing_closed(listing_b_good_till_cancelled);
+        }
+    }
+
+    // Close vaults of fully filled listings
+    if !is_partial_a {
+        harvest_withheld_fees(
+            &ctx.accounts.vault_a,
+            &ctx.accounts.token_mint_a,
+            &ctx.accounts.token_program_a,
+        )?;
+        close_token_account(
+            &ctx.accounts.vault_a,
+            &ctx.accounts.maker_a.to_account_info(),
+            &listing_a_account_info,
+            &ctx.accounts.token_program_a,
+            Some(listing_a_signer_seeds),
+        )?;
+    }
+    if !is_partial_b {
+        harvest_withheld_fees(
+            &ctx.accounts.vault_b,
+            &ctx.accounts.token_mint_b,
+            &ctx.accounts.token_program_b,
+        )?;
+        close_token_account(
+            &ctx.accounts.vault_b,
+            &ctx.accounts.maker_b.to_account_info(),
+            &listing_b_account_info,
+            &ctx.accounts.token_program_b,
+            Some(listing_b_signer_seeds),
+        )?;
+    }
+
+    emit!(ListingsMatched {
+        listing_a_id,
+        maker_a: maker_a_key,
+        listing_b_id,
+        maker_b: maker_b_key,
+        matched_by: ctx.accounts.cranker.key(),
+        token_mint_a: ctx.accounts.token_mint_a.key(),
+        token_mint_b: ctx.accounts.token_mint_b.key(),
+        amount_a,
+        amount_b,
+        amount_b_to_maker_a,
+        surplus,
+        fee_a,
+        fee_b,
+        new_status_a: listing_a_status,
+        new_status_b: listing_b_status,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "MATCH: {} x {} a={} b={} surplus={}",
+        listing_a_id,
+        listing_b_id,
+        amount_a,
+        amount_b,
+        surplus
+    );
+
+    Ok(())
+}
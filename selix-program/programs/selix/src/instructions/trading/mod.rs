@@ -1,3 +1,9 @@
+pub mod execute_route;
 pub mod execute_swap;
+pub mod match_listings;
+pub mod sweep_listings;
 
+pub use execute_route::*;
 pub use execute_swap::*;
+pub use match_listings::*;
+pub use sweep_listings::*;
@@ -0,0 +1,350 @@
+use crate::{
+    constants::*,
+    errors::SelixError,
+    events::{SweepExecuted, SweepListingSkipped},
+    instructions::trading::execute_route::{
+        load_maker_profile, settle_remaining_fill, validate_remaining_listing, RemainingFill,
+    },
+    state::{Listing, Platform, SweepSkipReason, UserProfile},
+    utils::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Accounts passed in `remaining_accounts` for every listing, in order:
+/// listing, vault, maker, maker profile (program ID when omitted) and
+/// maker token account (destination mint)
+pub const SWEEP_LISTING_ACCOUNTS: usize = 5;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SweepListingsParams {
+    /// Most destination tokens paid across all fills, fees included
+    pub max_amount_in: u64,
+    /// Worst accepted price: `limit_amount_destination` per `limit_amount_source`
+    pub limit_amount_source: u64,
+    pub limit_amount_destination: u64,
+    /// Minimum source tokens received across all fills
+    pub min_amount_out: u64,
+    /// Number of listings in `remaining_accounts`
+    pub listings: u8,
+}
+
+#[derive(Accounts)]
+pub struct SweepListings<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, taker.key().as_ref()],
+        bump = taker_profile.bump,
+    )]
+    pub taker_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Box<Account<'info, Platform>>,
+
+    /// CHECK: Fee collector wallet. Validated against platform state.
+    #[account(
+        constraint = fee_collector.key() == platform.fee_collector
+            @ SelixError::UnauthorizedAuthority
+    )]
+    pub fee_collector: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_source,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program_source,
+    )]
+    pub taker_token_account_source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint_destination,
+        token::authority = taker,
+        token::token_program = token_program_destination,
+    )]
+    pub taker_token_account_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_destination,
+        associated_token::authority = fee_collector,
+        associated_token::token_program = token_program_destination,
+    )]
+    pub fee_collector_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Writable so withheld transfer fees can be harvested before vaults close
+    #[account(mut, mint::token_program = token_program_source)]
+    pub token_mint_source: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program_destination)]
+    pub token_mint_destination: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program_source: Interface<'info, TokenInterface>,
+    pub token_program_destination: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Map the error of a listing the taker cannot fill at all to a skip reason.
+/// Errors in the sweep itself are returned unchanged.
+fn ineligible_skip_reason(error: Error) -> Result<SweepSkipReason> {
+    if error == SelixError::ListingNotStarted.into() || error == SelixError::ListingNotActive.into()
+    {
+        Ok(SweepSkipReason::NotActive)
+    } else if error == SelixError::ListingExpired.into() {
+        Ok(SweepSkipReason::Expired)
+    } else if error == SelixError::ListingCancelledByEpoch.into() {
+        Ok(SweepSkipReason::Cancelled)
+    } else if error == SelixError::TakerNotAllowed.into() {
+        Ok(SweepSkipReason::TakerNotAllowed)
+    } else {
+        Err(error)
+    }
+}
+
+fn emit_listing_skipped(taker: Pubkey, listing: Pubkey, reason: SweepSkipReason, now: i64) {
+    emit!(SweepListingSkipped {
+        taker,
+        listing,
+        reason,
+        timestamp: now,
+    });
+    msg!("SWEEP SKIPPED: listing={} reason={:?}", listing, reason);
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SweepListings<'info>>,
+    params: SweepListingsParams,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate platform not paused
+    validate_not_paused(&ctx.accounts.platform)?;
+
+    // Validate sweep limits
+    let listing_count = params.listings as usize;
+    require!(
+        (1..=MAX_SWEEP_LISTINGS).contains(&listing_count),
+        SelixError::InvalidSweep
+    );
+    require!(params.max_amount_in > 0, SelixError::InvalidAmount);
+    require!(
+        params.limit_amount_source > 0 && params.limit_amount_destination > 0,
+        SelixError::InvalidAmount
+    );
+
+    let listing_accounts_len = listing_count * SWEEP_LISTING_ACCOUNTS;
+    require!(
+        ctx.remaining_accounts.len() >= listing_accounts_len,
+        SelixError::InvalidSweep
+    );
+    // Accounts after the listings are extra accounts for transfer-hook mints
+    let (listing_accounts, hook_accounts) = ctx.remaining_accounts.split_at(listing_accounts_len);
+
+    let taker_key = ctx.accounts.taker.key();
+    let token_mint_source_key = ctx.accounts.token_mint_source.key();
+    let token_mint_destination_key = ctx.accounts.token_mint_destination.key();
+
+    // Load every listing and quote it at the current time
+    let mut listings: Vec<Account<'info, Listing>> = Vec::with_capacity(listing_count);
+    let mut quotes = Vec::with_capacity(listing_count);
+    for (index, accounts) in listing_accounts.chunks(SWEEP_LISTING_ACCOUNTS).enumerate() {
+        let mut listing: Account<'info, Listing> = Account::try_from(&accounts[0])?;
+        require!(
+            listings.iter().all(|other| other.key() != listing.key()),
+            SelixError::InvalidSweep
+        );
+        require!(
+            listing.token_mint_source == token_mint_source_key
+                && listing.token_mint_destination == token_mint_destination_key,
+            SelixError::InvalidSweep
+        );
+
+        // Expired, inactive, cancelled or private listings are skipped, not fatal
+        let eligibility = validate_remaining_listing(&mut listing, &taker_key, current_time)
+            .and_then(|()| {
+                let maker_profile = load_maker_profile(&accounts[3])?;
+                listing.validate_cancel_epoch(maker_profile.as_deref())
+            });
+        if let Err(error) = eligibility {
+            let reason = ineligible_skip_reason(error)?;
+            emit_listing_skipped(taker_key, listing.key(), reason, current_time);
+            listings.push(listing);
+            continue;
+        }
+
+        let amount_source_remaining = listing.amount_source_remaining;
+        let amount_destination_remaining =
+            listing.current_amount_destination_remaining(current_time)?;
+        quotes.push((index, amount_source_remaining, amount_destination_remaining));
+        listings.push(listing);
+    }
+
+    // Best price first: fewest destination tokens per source token
+    quotes.sort_by(|a, b| compare_prices(a.1, a.2, b.1, b.2));
+
+    let mut amount_in: u64 = 0;
+    let mut amount_out: u64 = 0;
+    let mut fee_total: u64 = 0;
+    let mut filled = Vec::with_capacity(listing_count);
+
+    for (index, amount_source_remaining, amount_destination_remaining) in quotes {
+        let listing = &mut listings[index];
+        let listing_key = listing.key();
+
+        // Spend the remaining budget, up to the listing's largest fill
+        let budget = params.max_amount_in - amount_in;
+        let max_fill = listing.max_fill_amount(current_time)?;
+        let (amount_source, amount_destination, _) = calculate_budget_fill(
+            amount_source_remaining,
+            amount_destination_remaining,
+            max_fill,
+            budget,
+        )?;
+
+        // Report listings the sweep passes over, so a short fill can be explained
+        let skip_reason = if compare_prices(
+            amount_source_remaining,
+            amount_destination_remaining,
+            params.limit_amount_source,
+            params.limit_amount_destination,
+        ) == std::cmp::Ordering::Greater
+        {
+            Some(SweepSkipReason::PriceAboveLimit)
+        } else if budget == 0 {
+            Some(SweepSkipReason::BudgetSpent)
+        } else if max_fill == 0 {
+            Some(SweepSkipReason::NothingFillable)
+        } else if amount_source == 0 || amount_destination == 0 {
+            Some(SweepSkipReason::FillTooSmall)
+        } else {
+            match listing.validate_fill_amount(amount_source, current_time) {
+                Ok(()) => None,
+                Err(error) if error == SelixError::FillAmountTooSmall.into() => {
+                    Some(SweepSkipReason::FillTooSmall)
+                }
+                Err(error) if error == SelixError::PartialFillNotAllowed.into() => {
+                    Some(SweepSkipReason::AllOrNone)
+                }
+                Err(error) => return Err(error),
+            }
+        };
+        if let Some(reason) = skip_reason {
+            emit_listing_skipped(taker_key, listing_key, reason, current_time);
+            continue;
+        }
+
+        let accounts =
+            &listing_accounts[index * SWEEP_LISTING_ACCOUNTS..(index + 1) * SWEEP_LISTING_ACCOUNTS];
+        let vault: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[1])?;
+        let maker = &accounts[2];
+        let maker_profile = &accounts[3];
+        let maker_token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[4])?;
+
+        require_keys_eq!(
+            maker.key(),
+            listing.maker,
+            SelixError::UnauthorizedAuthority
+        );
+        validate_token_account(&vault, &token_mint_source_key, &listing_key)?;
+        validate_token_account(
+            &maker_token_account,
+            &token_mint_destination_key,
+            &listing.maker,
+        )?;
+
+        // Check taker has sufficient balance
+        ctx.accounts.taker_token_account_destination.reload()?;
+        require!(
+            ctx.accounts.taker_token_account_destination.amount >= amount_destination,
+            SelixError::InsufficientTakerBalance
+        );
+
+        let (fee_amount, source_transfer_fee) = settle_remaining_fill(
+            RemainingFill {
+                taker: &ctx.accounts.taker.to_account_info(),
+                listing,
+                vault: &vault,
+                maker,
+                maker_profile,
+                maker_token_account: &maker_token_account,
+                fee_collector_token_account: &ctx.accounts.fee_collector_token_account,
+                taker_token_account_source: &ctx.accounts.taker_token_account_source,
+                taker_token_account_destination: &ctx.accounts.taker_token_account_destination,
+                token_mint_source: &ctx.accounts.token_mint_source,
+                token_mint_destination: &ctx.accounts.token_mint_destination,
+                token_program_source: &ctx.accounts.token_program_source,
+                token_program_destination: &ctx.accounts.token_program_destination,
+                hook_accounts,
+            },
+            &mut ctx.accounts.platform,
+            ctx.accounts
+                .taker_profile
+                .as_deref_mut()
+                .map(|profile| &mut **profile),
+            amount_source,
+            amount_destination,
+            current_time,
+            ctx.program_id,
+        )?;
+
+        amount_in = amount_in
+            .checked_add(amount_destination)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        amount_out = amount_out
+            .checked_add(
+                amount_source
+                    .checked_sub(source_transfer_fee)
+                    .ok_or(SelixError::ArithmeticUnderflow)?,
+            )
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        fee_total = fee_total
+            .checked_add(fee_amount)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        filled.push(listing_key);
+    }
+
+    require!(!filled.is_empty(), SelixError::NoFillableListings);
+
+    // Validate aggregate slippage
+    require!(
+        amount_out >= params.min_amount_out,
+        SelixError::SlippageExceeded
+    );
+
+    let fill_count = filled.len();
+    emit!(SweepExecuted {
+        taker: taker_key,
+        token_mint_source: token_mint_source_key,
+        token_mint_destination: token_mint_destination_key,
+        amount_in,
+        amount_out,
+        fee_amount: fee_total,
+        listings: filled,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "SWEEP: fills={} in={} out={} fee={}",
+        fill_count,
+        amount_in,
+        amount_out,
+        fee_total
+    );
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::{constants::*, errors::SelixError, events::CancelEpochBumped, state::UserProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct BumpCancelEpoch<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, user.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = user @ SelixError::UnauthorizedAuthority,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+}
+
+pub fn handler(ctx: Context<BumpCancelEpoch>) -> Result<()> {
+    let profile = &mut ctx.accounts.user_profile;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Every listing created under an older epoch stops being fillable
+    profile.cancel_epoch = profile
+        .cancel_epoch
+        .checked_add(1)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+    profile.last_activity_at = current_time;
+
+    emit!(CancelEpochBumped {
+        user: ctx.accounts.user.key(),
+        cancel_epoch: profile.cancel_epoch,
+        active_listings: profile.active_listings,
+        timestamp: current_time,
+    });
+
+    // User audit log
+    msg!("CANCEL EPOCH BUMPED");
+    msg!("---------------------------");
+    msg!("User: {}", ctx.accounts.user.key());
+    msg!("Cancel Epoch: {}", profile.cancel_epoch);
+    msg!("Listings Invalidated: {}", profile.active_listings);
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
@@ -62,6 +62,7 @@ pub fn handler(ctx: Context<InitializeUser>, params: InitializeUserParams) -> Re
     profile.swaps_executed = 0;
     profile.swaps_received = 0;
     profile.active_listings = 0;
+    profile.active_gtc_listings = 0;
     profile.volume_as_maker = 0;
     profile.volume_as_taker = 0;
     profile.total_fees_paid = 0;
@@ -69,6 +70,7 @@ pub fn handler(ctx: Context<InitializeUser>, params: InitializeUserParams) -> Re
     profile.default_slippage_bps = params.default_slippage_bps;
     profile.created_at = current_time;
     profile.last_activity_at = current_time;
+    profile.cancel_epoch = INITIAL_CANCEL_EPOCH;
     profile.bump = ctx.bumps.user_profile;
 
     emit!(UserProfileCreated {
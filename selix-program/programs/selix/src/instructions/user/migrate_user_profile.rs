@@ -0,0 +1,67 @@
+use crate::{constants::*, events::AccountMigrated, state::UserProfile, utils::*};
+use anchor_lang::prelude::*;
+
+/// Size of a user profile written before the cancel epoch (the last eight
+/// bytes of the layout) was appended
+const PRE_CANCEL_EPOCH_SIZE: usize = 8 + UserProfile::INIT_SPACE - 8;
+
+#[derive(Accounts)]
+pub struct MigrateUserProfile<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: May still use an older, shorter layout; owner, discriminator
+    /// and user are validated in the handler
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_profile: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateUserProfile>) -> Result<()> {
+    let profile = ctx.accounts.user_profile.to_account_info();
+    let user = ctx.accounts.user.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    validate_legacy_account(&profile, UserProfile::DISCRIMINATOR, &user)?;
+
+    let old_size = profile.data_len();
+    let new_size = 8 + UserProfile::INIT_SPACE;
+    grow_pda_account(
+        &ctx.accounts.user.to_account_info(),
+        &profile,
+        new_size,
+        &ctx.accounts.system_program,
+    )?;
+
+    // Profiles that predate the kill switch start at the initial epoch, so
+    // fills of their new listings must pass the profile
+    if old_size <= PRE_CANCEL_EPOCH_SIZE {
+        let mut data = profile.try_borrow_mut_data()?;
+        let mut state = UserProfile::try_deserialize(&mut &data[..])?;
+        state.cancel_epoch = INITIAL_CANCEL_EPOCH;
+        state.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(AccountMigrated {
+        account: profile.key(),
+        owner: user,
+        old_size: old_size as u64,
+        new_size: profile.data_len() as u64,
+        timestamp: current_time,
+    });
+
+    // User audit log
+    msg!("USER PROFILE MIGRATED");
+    msg!("---------------------------");
+    msg!("User: {}", user);
+    msg!("Old Size: {}", old_size);
+    msg!("New Size: {}", profile.data_len());
+    msg!("Timestamp: {}", current_time);
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 #![allow(ambiguous_glob_reexports)]
 
+pub mod bump_cancel_epoch;
 pub mod initialize_user;
+pub mod migrate_user_profile;
 pub mod update_preferences;
 
+pub use bump_cancel_epoch::*;
 pub use initialize_user::*;
+pub use migrate_user_profile::*;
 pub use update_preferences::*;
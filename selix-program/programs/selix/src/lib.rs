@@ -50,35 +50,180 @@ pub mod selix {
         instructions::admin::manage_whitelist::handler(ctx, is_whitelisted)
     }
 
+    /// Grow the platform account to the current layout after an upgrade
+    pub fn migrate_platform(ctx: Context<MigratePlatform>) -> Result<()> {
+        instructions::admin::migrate_platform::handler(ctx)
+    }
+
     // Listing Instructions
 
     /// Create a new swap listing
-    pub fn create_listing(ctx: Context<CreateListing>, params: CreateListingParams) -> Result<()> {
+    pub fn create_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateListing<'info>>,
+        params: CreateListingParams,
+    ) -> Result<()> {
         instructions::listing::create_listing::handler(ctx, params)
     }
 
+    /// Create several listings for one pair in a single instruction
+    pub fn create_listings_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateListingsBatch<'info>>,
+        params: CreateListingsBatchParams,
+    ) -> Result<()> {
+        instructions::listing::create_listings_batch::handler(ctx, params)
+    }
+
     /// Update an existing listing
     pub fn update_listing(ctx: Context<UpdateListing>, params: UpdateListingParams) -> Result<()> {
         instructions::listing::update_listing::handler(ctx, params)
     }
 
+    /// Deposit more source tokens into a listing at the same price
+    pub fn top_up_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResizeListing<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::listing::resize_listing::top_up_handler(ctx, amount)
+    }
+
+    /// Withdraw part of a listing's unfilled source tokens at the same price
+    pub fn withdraw_from_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResizeListing<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::listing::resize_listing::withdraw_handler(ctx, amount)
+    }
+
     /// Cancel a listing and return funds
-    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+    pub fn cancel_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelListing<'info>>,
+    ) -> Result<()> {
         instructions::listing::cancel_listing::handler(ctx)
     }
 
+    /// Cancel several of the maker's listings and return their funds
+    pub fn cancel_all_listings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelAllListings<'info>>,
+        params: CancelAllListingsParams,
+    ) -> Result<()> {
+        instructions::listing::cancel_all_listings::handler(ctx, params)
+    }
+
+    /// Activate a scheduled listing once its start time has passed (anyone can call)
+    pub fn activate_listing(ctx: Context<ActivateListing>) -> Result<()> {
+        instructions::listing::activate_listing::handler(ctx)
+    }
+
+    /// Activate a stop-loss listing once the oracle price crosses its trigger (anyone can call)
+    pub fn trigger_listing(ctx: Context<TriggerListing>) -> Result<()> {
+        instructions::listing::trigger_listing::handler(ctx)
+    }
+
     /// Close an expired listing (anyone can call)
-    pub fn close_expired_listing(ctx: Context<CloseExpiredListing>) -> Result<()> {
+    pub fn close_expired_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExpiredListing<'info>>,
+    ) -> Result<()> {
         instructions::listing::close_expired::handler(ctx)
     }
 
     // Trading Instructions
 
     /// Execute a swap (full or partial)
-    pub fn execute_swap(ctx: Context<ExecuteSwap>, params: ExecuteSwapParams) -> Result<()> {
+    pub fn execute_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteSwap<'info>>,
+        params: ExecuteSwapParams,
+    ) -> Result<()> {
         instructions::trading::execute_swap::handler(ctx, params)
     }
 
+    /// Fill a chain of listings (A→B→C) with one end-to-end slippage bound
+    pub fn execute_route<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteRoute<'info>>,
+        params: ExecuteRouteParams,
+    ) -> Result<()> {
+        instructions::trading::execute_route::handler(ctx, params)
+    }
+
+    /// Fill listings of one pair best-price-first up to a budget and a worst price
+    pub fn sweep_listings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepListings<'info>>,
+        params: SweepListingsParams,
+    ) -> Result<()> {
+        instructions::trading::sweep_listings::handler(ctx, params)
+    }
+
+    /// Settle two crossing listings against each other (anyone can call)
+    pub fn match_listings<'info>(
+        ctx: Context<'_, '_, '_, 'info, MatchListings<'info>>,
+    ) -> Result<()> {
+        instructions::trading::match_listings::handler(ctx)
+    }
+
+    // Offer Instructions
+
+    /// Escrow a counter-offer against a listing at the taker's own price
+    pub fn make_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, MakeOffer<'info>>,
+        params: MakeOfferParams,
+    ) -> Result<()> {
+        instructions::offer::make_offer::handler(ctx, params)
+    }
+
+    /// Accept a counter-offer and settle it against the listing
+    pub fn accept_offer<'info>(ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>) -> Result<()> {
+        instructions::offer::accept_offer::handler(ctx)
+    }
+
+    /// Withdraw a counter-offer and return the escrow
+    pub fn withdraw_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawOffer<'info>>,
+    ) -> Result<()> {
+        instructions::offer::withdraw_offer::handler(ctx)
+    }
+
+    /// Close an expired counter-offer (anyone can call)
+    pub fn close_expired_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExpiredOffer<'info>>,
+    ) -> Result<()> {
+        instructions::offer::close_expired_offer::handler(ctx)
+    }
+
+    // Signed Order Instructions
+
+    /// Create the maker's nonce account for off-chain signed orders
+    pub fn initialize_order_nonces(ctx: Context<InitializeOrderNonces>) -> Result<()> {
+        instructions::signed_order::initialize_order_nonces::handler(ctx)
+    }
+
+    /// Settle an Ed25519-signed maker order against the maker's delegate approval
+    pub fn fill_signed_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillSignedOrder<'info>>,
+        order: SignedOrder,
+    ) -> Result<()> {
+        instructions::signed_order::fill_signed_order::handler(ctx, order)
+    }
+
+    /// Settle a short-lived Ed25519-signed RFQ quote for the requesting taker
+    pub fn fill_rfq<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillSignedOrder<'info>>,
+        quote: RfqQuote,
+    ) -> Result<()> {
+        instructions::signed_order::fill_rfq::handler(ctx, quote)
+    }
+
+    /// Cancel a single signed order by its nonce
+    pub fn cancel_signed_order(ctx: Context<CancelSignedOrder>, nonce: u64) -> Result<()> {
+        instructions::signed_order::cancel_signed_order::cancel_handler(ctx, nonce)
+    }
+
+    /// Cancel every signed order with a nonce below the new floor
+    pub fn cancel_signed_orders_below(
+        ctx: Context<CancelSignedOrder>,
+        nonce_floor: u64,
+    ) -> Result<()> {
+        instructions::signed_order::cancel_signed_order::cancel_below_handler(ctx, nonce_floor)
+    }
+
     // User Instructions
 
     /// Initialize user profile
@@ -96,4 +241,14 @@ pub mod selix {
     ) -> Result<()> {
         instructions::user::update_preferences::handler(ctx, params)
     }
+
+    /// Grow the caller's user profile to the current layout after an upgrade
+    pub fn migrate_user_profile(ctx: Context<MigrateUserProfile>) -> Result<()> {
+        instructions::user::migrate_user_profile::handler(ctx)
+    }
+
+    /// Invalidate every open listing of the caller in one instruction
+    pub fn bump_cancel_epoch(ctx: Context<BumpCancelEpoch>) -> Result<()> {
+        instructions::user::bump_cancel_epoch::handler(ctx)
+    }
 }
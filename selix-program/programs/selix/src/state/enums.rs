@@ -22,13 +22,75 @@ impl Default for ListingStatus {
     }
 }
 
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default,
+)]
+pub enum ListingKind {
+    /// Fixed price set by the destination amounts
+    #[default]
+    FixedPrice,
+    /// Price decays from the start amount to a floor amount by expiry
+    DutchAuction,
+    /// Price read from an oracle price feed at fill time
+    OraclePegged,
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default,
+)]
+pub enum ListingSide {
+    /// Maker sells the base token (vault holds base, taker pays quote)
+    #[default]
+    Ask,
+    /// Maker buys the base token (vault holds quote, taker delivers base)
+    Bid,
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default,
+)]
+pub enum TriggerCondition {
+    /// No trigger; the listing activates on its start time
+    #[default]
+    None,
+    /// Activates once the oracle price is at or below the trigger price (stop-loss)
+    AtOrBelow,
+    /// Activates once the oracle price is at or above the trigger price
+    AtOrAbove,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum SweepSkipReason {
+    /// Listing price is worse than the sweep limit
+    PriceAboveLimit,
+    /// `max_amount_in` was spent on better-priced listings
+    BudgetSpent,
+    /// Nothing is displayed or unlocked for filling yet
+    NothingFillable,
+    /// The remaining budget buys less than the listing's minimum fill
+    FillTooSmall,
+    /// The remaining budget cannot take the whole all-or-none listing
+    AllOrNone,
+    /// Listing is pending, filled or otherwise not tradeable
+    NotActive,
+    /// Listing has expired
+    Expired,
+    /// Listing was invalidated by the maker's cancel epoch
+    Cancelled,
+    /// Private listing that does not allow the taker
+    TakerNotAllowed,
+}
+
 impl ListingStatus {
     pub fn is_active(&self) -> bool {
         matches!(self, ListingStatus::Active | ListingStatus::PartiallyFilled)
     }
 
     pub fn can_be_cancelled(&self) -> bool {
-        matches!(self, ListingStatus::Active | ListingStatus::PartiallyFilled)
+        matches!(
+            self,
+            ListingStatus::Pending | ListingStatus::Active | ListingStatus::PartiallyFilled
+        )
     }
 
     pub fn can_be_traded(&self) -> bool {
@@ -1,4 +1,13 @@
-use super::enums::ListingStatus;
+use super::enums::{ListingKind, ListingSide, ListingStatus, TriggerCondition};
+use super::UserProfile;
+use crate::{
+    constants::MAX_ALLOWED_TAKERS,
+    errors::SelixError,
+    utils::{
+        calculate_dutch_auction_amount, calculate_oracle_amount, calculate_partial_amounts,
+        calculate_unlocked_amount, is_trigger_met, OraclePrice,
+    },
+};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -16,6 +25,18 @@ pub struct Listing {
     /// Token being requested (destination)
     pub token_mint_destination: Pubkey,
 
+    /// Source mint is an NFT (0 decimals, supply 1)
+    pub source_is_nft: bool,
+
+    /// Destination mint is an NFT (0 decimals, supply 1)
+    pub destination_is_nft: bool,
+
+    /// Any verified NFT of the `token_mint_destination` collection is accepted
+    pub destination_is_collection: bool,
+
+    /// Ask escrows the base token, Bid escrows the quote token
+    pub side: ListingSide,
+
     /// Original amount of source tokens
     pub amount_source_total: u64,
 
@@ -34,9 +55,59 @@ pub struct Listing {
     /// Maximum slippage tolerance in basis points
     pub max_slippage_bps: u16,
 
-    /// Listing expiration timestamp
+    /// Only a fill of the full remaining amount is accepted (fill-or-kill)
+    pub all_or_none: bool,
+
+    /// Iceberg: visible clip size refilled from the hidden reserve (0 = fully displayed)
+    pub display_amount: u64,
+
+    /// Iceberg: source amount currently displayed and fillable
+    pub amount_source_displayed: u64,
+
+    /// Pricing model
+    pub kind: ListingKind,
+
+    /// Dutch auction: destination amount for the full listing at expiry
+    pub auction_floor_amount_destination: u64,
+
+    /// Dutch auction: price step interval in seconds (0 = linear decay)
+    pub auction_step_seconds: i64,
+
+    /// Oracle pegged: Pyth-style price feed for source priced in destination
+    pub price_feed: Pubkey,
+
+    /// Oracle pegged: feed id read from `price_feed` on creation; every
+    /// later price must come from the same feed
+    pub price_feed_id: [u8; 32],
+
+    /// Oracle pegged: premium (positive) or discount (negative) in basis points
+    pub oracle_premium_bps: i16,
+
+    /// Stop-loss: oracle condition that moves the listing from pending to active
+    pub trigger_condition: TriggerCondition,
+
+    /// Stop-loss: trigger price in the price feed's units and exponent
+    pub trigger_price: i64,
+
+    /// Takers allowed to fill this listing (empty means anyone)
+    #[max_len(MAX_ALLOWED_TAKERS)]
+    pub allowed_takers: Vec<Pubkey>,
+
+    /// Timestamp from which the listing can be filled
+    pub starts_at: i64,
+
+    /// Listing expiration timestamp (i64::MAX for good-till-cancelled)
     pub expires_at: i64,
 
+    /// Listing has no expiry and stays open until cancelled or filled
+    pub good_till_cancelled: bool,
+
+    /// Release schedule: nothing can be filled before this timestamp
+    pub release_cliff_at: i64,
+
+    /// Release schedule: fully unlocked at this timestamp (0 = no schedule)
+    pub release_end_at: i64,
+
     /// Listing creation timestamp
     pub created_at: i64,
 
@@ -49,13 +120,16 @@ pub struct Listing {
     /// Number of partial fills executed
     pub fill_count: u16,
 
+    /// Maker's cancel epoch when the listing was created
+    pub cancel_epoch: u64,
+
     /// PDA bump
     pub bump: u8,
 }
 
 impl Listing {
     pub fn is_expired(&self, current_time: i64) -> bool {
-        current_time >= self.expires_at
+        !self.good_till_cancelled && current_time >= self.expires_at
     }
 
     pub fn is_active(&self) -> bool {
@@ -66,31 +140,351 @@ impl Listing {
         self.status.can_be_traded() && !self.is_expired(current_time)
     }
 
+    /// Listing created before the maker's last `bump_cancel_epoch`
+    pub fn is_cancelled_by_epoch(&self, maker_cancel_epoch: u64) -> bool {
+        self.cancel_epoch < maker_cancel_epoch
+    }
+
+    /// Fills require the listing to be created under the maker's current epoch.
+    /// The maker profile may only be omitted for listings recorded at epoch 0
+    pub fn validate_cancel_epoch(&self, maker_profile: Option<&UserProfile>) -> Result<()> {
+        let Some(maker_profile) = maker_profile else {
+            require!(self.cancel_epoch == 0, SelixError::UserProfileNotFound);
+            return Ok(());
+        };
+        require_keys_eq!(
+            maker_profile.user,
+            self.maker,
+            SelixError::UnauthorizedAuthority
+        );
+        require!(
+            !self.is_cancelled_by_epoch(maker_profile.cancel_epoch),
+            SelixError::ListingCancelledByEpoch
+        );
+        Ok(())
+    }
+
+    /// Pending listing whose start time has passed and whose trigger, if any,
+    /// is met by the given oracle price
+    pub fn is_ready_to_activate(&self, current_time: i64, price: Option<&OraclePrice>) -> bool {
+        self.status == ListingStatus::Pending
+            && current_time >= self.starts_at
+            && (!self.has_trigger() || price.is_some_and(|price| self.is_triggered(price)))
+    }
+
+    pub fn has_trigger(&self) -> bool {
+        self.trigger_condition != TriggerCondition::None
+    }
+
+    pub fn is_triggered(&self, price: &OraclePrice) -> bool {
+        is_trigger_met(self.trigger_condition, self.trigger_price, price.price)
+    }
+
+    /// Oracle-pegged listings and untriggered stop-loss listings read the price feed
+    pub fn needs_price_feed(&self) -> bool {
+        self.kind == ListingKind::OraclePegged
+            || (self.status == ListingStatus::Pending && self.has_trigger())
+    }
+
+    pub fn is_private(&self) -> bool {
+        !self.allowed_takers.is_empty()
+    }
+
+    pub fn is_taker_allowed(&self, taker: &Pubkey) -> bool {
+        !self.is_private() || self.allowed_takers.contains(taker)
+    }
+
     pub fn calculate_exchange_rate(&self) -> Result<u128> {
         if self.amount_source_remaining == 0 {
-            return Err(error!(crate::errors::SelixError::InvalidAmount));
+            return Err(error!(SelixError::InvalidAmount));
         }
 
         let rate = (self.amount_destination_remaining as u128)
             .checked_mul(crate::constants::BPS_DENOMINATOR as u128)
-            .ok_or(crate::errors::SelixError::ArithmeticOverflow)?
+            .ok_or(SelixError::ArithmeticOverflow)?
             .checked_div(self.amount_source_remaining as u128)
-            .ok_or(crate::errors::SelixError::DivisionByZero)?;
+            .ok_or(SelixError::DivisionByZero)?;
 
         Ok(rate)
     }
 
-    pub fn update_after_fill(&mut self, source_filled: u64, destination_filled: u64) {
-        self.amount_source_remaining = self.amount_source_remaining.saturating_sub(source_filled);
+    /// Destination amount currently asked for the remaining source tokens
+    /// Oracle-pegged listings are priced with `oracle_amount_destination_remaining`
+    pub fn current_amount_destination_remaining(&self, current_time: i64) -> Result<u64> {
+        match self.kind {
+            ListingKind::FixedPrice => Ok(self.amount_destination_remaining),
+            ListingKind::OraclePegged => Err(error!(SelixError::InvalidPriceFeed)),
+            ListingKind::DutchAuction => {
+                let (_, floor_remaining) = calculate_partial_amounts(
+                    self.amount_source_total,
+                    self.auction_floor_amount_destination,
+                    self.amount_source_remaining,
+                )?;
+
+                calculate_dutch_auction_amount(
+                    self.amount_destination_remaining,
+                    floor_remaining,
+                    self.starts_at,
+                    self.expires_at,
+                    self.auction_step_seconds,
+                    current_time,
+                )
+            }
+        }
+    }
+
+    /// Destination amount asked for the remaining source tokens at an oracle price
+    pub fn oracle_amount_destination_remaining(
+        &self,
+        price: &OraclePrice,
+        source_decimals: u8,
+        destination_decimals: u8,
+    ) -> Result<u64> {
+        calculate_oracle_amount(
+            self.amount_source_remaining,
+            price.price,
+            price.exponent,
+            source_decimals,
+            destination_decimals,
+            self.oracle_premium_bps,
+        )
+    }
+
+    /// Remaining destination amount consumed by a fill
+    /// (Dutch auctions track the remaining amount at the start price and
+    /// oracle-pegged listings at the price seen on creation)
+    pub fn destination_consumed_by_fill(
+        &self,
+        amount_source: u64,
+        amount_destination: u64,
+    ) -> Result<u64> {
+        match self.kind {
+            ListingKind::FixedPrice => Ok(amount_destination),
+            ListingKind::DutchAuction | ListingKind::OraclePegged => Ok(calculate_partial_amounts(
+                self.amount_source_remaining,
+                self.amount_destination_remaining,
+                amount_source,
+            )?
+            .1),
+        }
+    }
+
+    pub fn is_iceberg(&self) -> bool {
+        self.display_amount > 0
+    }
+
+    /// Source amount shown to takers (the current clip for iceberg listings)
+    pub fn visible_amount_source(&self) -> u64 {
+        if self.is_iceberg() {
+            self.amount_source_displayed
+        } else {
+            self.amount_source_remaining
+        }
+    }
+
+    /// Iceberg listings: reduce the clip by a fill and refill it from the
+    /// hidden reserve once exhausted
+    pub fn consume_displayed(&mut self, amount_source: u64) -> Result<()> {
+        if !self.is_iceberg() {
+            return Ok(());
+        }
+
+        self.amount_source_displayed = self
+            .amount_source_displayed
+            .checked_sub(amount_source)
+            .ok_or(SelixError::AmountExceedsDisplayed)?;
+
+        if self.amount_source_displayed == 0 {
+            self.amount_source_displayed = self.display_amount.min(self.amount_source_remaining);
+        }
+
+        Ok(())
+    }
+
+    pub fn has_release_schedule(&self) -> bool {
+        self.release_end_at > 0
+    }
+
+    /// Source amount that can be filled now under the release schedule
+    pub fn available_to_fill(&self, current_time: i64) -> Result<u64> {
+        if !self.has_release_schedule() {
+            return Ok(self.amount_source_remaining);
+        }
+
+        let unlocked = calculate_unlocked_amount(
+            self.amount_source_total,
+            self.starts_at,
+            self.release_cliff_at,
+            self.release_end_at,
+            current_time,
+        )?;
+        let filled = self
+            .amount_source_total
+            .saturating_sub(self.amount_source_remaining);
+
+        Ok(unlocked
+            .saturating_sub(filled)
+            .min(self.amount_source_remaining))
+    }
+
+    /// Largest source amount a single fill can take right now
+    pub fn max_fill_amount(&self, current_time: i64) -> Result<u64> {
+        Ok(self
+            .visible_amount_source()
+            .min(self.available_to_fill(current_time)?))
+    }
+
+    /// Validate a fill against the remaining, displayed and unlocked inventory
+    pub fn validate_fill_amount(&self, amount_source: u64, current_time: i64) -> Result<()> {
+        require!(amount_source > 0, SelixError::InvalidAmount);
+        require!(
+            amount_source <= self.amount_source_remaining,
+            SelixError::SwapAmountExceedsRemaining
+        );
+        require!(
+            amount_source >= self.min_fill_amount,
+            SelixError::FillAmountTooSmall
+        );
+        require!(
+            amount_source <= self.visible_amount_source(),
+            SelixError::AmountExceedsDisplayed
+        );
+        require!(
+            !self.all_or_none || amount_source == self.amount_source_remaining,
+            SelixError::PartialFillNotAllowed
+        );
+        require!(
+            amount_source <= self.available_to_fill(current_time)?,
+            SelixError::AmountNotUnlocked
+        );
+        Ok(())
+    }
+
+    /// Book a fill against the remaining inventory
+    /// Returns true while source inventory remains
+    pub fn apply_fill(
+        &mut self,
+        amount_source: u64,
+        destination_consumed: u64,
+        current_time: i64,
+    ) -> Result<bool> {
+        self.amount_source_remaining = self
+            .amount_source_remaining
+            .checked_sub(amount_source)
+            .ok_or(SelixError::ArithmeticUnderflow)?;
         self.amount_destination_remaining = self
             .amount_destination_remaining
-            .saturating_sub(destination_filled);
-        self.fill_count = self.fill_count.saturating_add(1);
+            .checked_sub(destination_consumed)
+            .ok_or(SelixError::ArithmeticUnderflow)?;
+        self.consume_displayed(amount_source)?;
+        self.fill_count = self
+            .fill_count
+            .checked_add(1)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.updated_at = current_time;
 
-        if self.amount_source_remaining == 0 {
-            self.status = ListingStatus::Completed;
+        let is_partial = self.amount_source_remaining > 0;
+        self.status = if is_partial {
+            ListingStatus::PartiallyFilled
         } else {
-            self.status = ListingStatus::PartiallyFilled;
+            ListingStatus::Completed
+        };
+
+        Ok(is_partial)
+    }
+
+    /// Quote token amount of a fill, used for volume accounting
+    pub fn quote_amount(&self, amount_source: u64, amount_destination: u64) -> u64 {
+        match self.side {
+            ListingSide::Ask => amount_destination,
+            ListingSide::Bid => amount_source,
         }
     }
+
+    /// Add source inventory at the current price
+    /// Returns the destination amount added
+    pub fn increase_inventory(&mut self, amount_source: u64) -> Result<u64> {
+        let (_, amount_destination) = calculate_partial_amounts(
+            self.amount_source_remaining,
+            self.amount_destination_remaining,
+            amount_source,
+        )?;
+        let (_, auction_floor_delta) = calculate_partial_amounts(
+            self.amount_source_total,
+            self.auction_floor_amount_destination,
+            amount_source,
+        )?;
+
+        self.amount_source_total = self
+            .amount_source_total
+            .checked_add(amount_source)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.amount_source_remaining = self
+            .amount_source_remaining
+            .checked_add(amount_source)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.amount_destination_total = self
+            .amount_destination_total
+            .checked_add(amount_destination)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.amount_destination_remaining = self
+            .amount_destination_remaining
+            .checked_add(amount_destination)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.auction_floor_amount_destination = self
+            .auction_floor_amount_destination
+            .checked_add(auction_floor_delta)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+
+        Ok(amount_destination)
+    }
+
+    /// Remove unfilled source inventory at the current price
+    /// Returns the destination amount removed
+    pub fn decrease_inventory(&mut self, amount_source: u64) -> Result<u64> {
+        require!(
+            amount_source < self.amount_source_remaining,
+            SelixError::WithdrawAmountTooLarge
+        );
+
+        let (_, amount_destination) = calculate_partial_amounts(
+            self.amount_source_remaining,
+            self.amount_destination_remaining,
+            amount_source,
+        )?;
+        let (_, auction_floor_delta) = calculate_partial_amounts(
+            self.amount_source_total,
+            self.auction_floor_amount_destination,
+            amount_source,
+        )?;
+
+        self.amount_source_total = self
+            .amount_source_total
+            .checked_sub(amount_source)
+            .ok_or(SelixError::ArithmeticUnderflow)?;
+        self.amount_source_remaining = self
+            .amount_source_remaining
+            .checked_sub(amount_source)
+            .ok_or(SelixError::ArithmeticUnderflow)?;
+        self.amount_destination_total = self
+            .amount_destination_total
+            .checked_sub(amount_destination)
+            .ok_or(SelixError::ArithmeticUnderflow)?;
+        self.amount_destination_remaining = self
+            .amount_destination_remaining
+            .checked_sub(amount_destination)
+            .ok_or(SelixError::ArithmeticUnderflow)?;
+        self.auction_floor_amount_destination = self
+            .auction_floor_amount_destination
+            .checked_sub(auction_floor_delta)
+            .ok_or(SelixError::ArithmeticUnderflow)?;
+
+        // Keep the clip and minimum fill within the smaller inventory
+        self.amount_source_displayed = self
+            .amount_source_displayed
+            .min(self.amount_source_remaining);
+        self.min_fill_amount = self.min_fill_amount.min(self.amount_source_remaining);
+
+        Ok(amount_destination)
+    }
 }
@@ -1,11 +1,15 @@
 pub mod enums;
 pub mod listing;
+pub mod offer;
+pub mod order_nonces;
 pub mod platform;
 pub mod user_profile;
 pub mod whitelist;
 
 pub use enums::*;
 pub use listing::*;
+pub use offer::*;
+pub use order_nonces::*;
 pub use platform::*;
 pub use user_profile::*;
 pub use whitelist::*;
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Offer {
+    /// Offer identifier, unique per taker and listing
+    pub id: u64,
+
+    /// Listing the offer is made against
+    pub listing: Pubkey,
+
+    /// Identifier of the listing
+    pub listing_id: u64,
+
+    /// Listing maker who can accept the offer
+    pub maker: Pubkey,
+
+    /// Offer creator
+    pub taker: Pubkey,
+
+    /// Token requested from the listing (listing source)
+    pub token_mint_source: Pubkey,
+
+    /// Token escrowed as payment (listing destination)
+    pub token_mint_destination: Pubkey,
+
+    /// Source amount requested from the listing
+    pub amount_source: u64,
+
+    /// Destination amount escrowed and proposed as payment
+    pub amount_destination: u64,
+
+    /// Offer expiration timestamp
+    pub expires_at: i64,
+
+    /// Offer creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Offer {
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time >= self.expires_at
+    }
+}
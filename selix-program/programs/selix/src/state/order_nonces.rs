@@ -0,0 +1,31 @@
+use crate::{constants::ORDER_NONCE_BITMAP_WORDS, utils::*};
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct OrderNonces {
+    /// Maker whose signed orders these nonces protect
+    pub maker: Pubkey,
+
+    /// Nonces below this are used or cancelled
+    pub nonce_floor: u64,
+
+    /// Used or cancelled nonces from the floor upward, one bit each
+    pub nonce_bitmap: [u64; ORDER_NONCE_BITMAP_WORDS],
+
+    /// Account creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl OrderNonces {
+    pub fn use_nonce(&mut self, nonce: u64) -> Result<()> {
+        use_nonce(self.nonce_floor, &mut self.nonce_bitmap, nonce)
+    }
+
+    pub fn raise_floor(&mut self, new_floor: u64) -> Result<()> {
+        raise_nonce_floor(&mut self.nonce_floor, &mut self.nonce_bitmap, new_floor)
+    }
+}
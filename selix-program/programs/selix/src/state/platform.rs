@@ -1,3 +1,4 @@
+use crate::errors::SelixError;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -50,6 +51,18 @@ pub struct Platform {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Good-till-cancelled listings (no expiry) allowed
+    pub gtc_enabled: bool,
+
+    /// Maximum active good-till-cancelled listings per user
+    pub max_gtc_listings_per_user: u16,
+
+    /// Mint features that block listing creation (MINT_FEATURE_* bitmask)
+    pub rejected_mint_features: u8,
+
+    /// Mint features that are allowed but flagged with an event
+    pub flagged_mint_features: u8,
 }
 
 impl Platform {
@@ -64,4 +77,28 @@ impl Platform {
     pub fn validate_duration(&self, duration: i64) -> bool {
         duration >= self.min_listing_duration && duration <= self.max_listing_duration
     }
+
+    pub fn record_listing_created(&mut self) -> Result<()> {
+        self.total_listings_created = self
+            .total_listings_created
+            .checked_add(1)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_swap(&mut self, volume: u64, fee: u64) -> Result<()> {
+        self.total_swaps_executed = self
+            .total_swaps_executed
+            .checked_add(1)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.total_volume_traded = self
+            .total_volume_traded
+            .checked_add(volume as u128)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.total_fees_collected = self
+            .total_fees_collected
+            .checked_add(fee)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        Ok(())
+    }
 }
@@ -1,3 +1,4 @@
+use crate::errors::SelixError;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -47,6 +48,12 @@ pub struct UserProfile {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Current active good-till-cancelled listings count
+    pub active_gtc_listings: u16,
+
+    /// Listings created under an older epoch can no longer be filled
+    pub cancel_epoch: u64,
 }
 
 impl UserProfile {
@@ -63,20 +70,78 @@ impl UserProfile {
         self.active_listings = self.active_listings.saturating_sub(1);
     }
 
-    pub fn record_listing_cancelled(&mut self) {
-        self.listings_cancelled = self.listings_cancelled.saturating_add(1);
-        self.decrement_active_listings();
+    pub fn record_listing_created(
+        &mut self,
+        good_till_cancelled: bool,
+        current_time: i64,
+    ) -> Result<()> {
+        self.listings_created = self
+            .listings_created
+            .checked_add(1)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.active_listings = self
+            .active_listings
+            .checked_add(1)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        if good_till_cancelled {
+            self.active_gtc_listings = self
+                .active_gtc_listings
+                .checked_add(1)
+                .ok_or(SelixError::ArithmeticOverflow)?;
+        }
+        self.last_activity_at = current_time;
+        Ok(())
     }
 
-    pub fn record_swap_as_maker(&mut self, volume: u64) {
-        self.swaps_received = self.swaps_received.saturating_add(1);
-        self.volume_as_maker = self.volume_as_maker.saturating_add(volume as u128);
+    pub fn record_listing_cancelled(
+        &mut self,
+        good_till_cancelled: bool,
+        current_time: i64,
+    ) -> Result<()> {
+        self.listings_cancelled = self
+            .listings_cancelled
+            .checked_add(1)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.record_listing_closed(good_till_cancelled);
+        self.last_activity_at = current_time;
+        Ok(())
+    }
+
+    /// Filled or closed listings no longer count against the listing limits
+    pub fn record_listing_closed(&mut self, good_till_cancelled: bool) {
         self.decrement_active_listings();
+        if good_till_cancelled {
+            self.active_gtc_listings = self.active_gtc_listings.saturating_sub(1);
+        }
+    }
+
+    pub fn record_swap_as_maker(&mut self, volume: u64, current_time: i64) -> Result<()> {
+        self.swaps_received = self
+            .swaps_received
+            .checked_add(1)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.volume_as_maker = self
+            .volume_as_maker
+            .checked_add(volume as u128)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.last_activity_at = current_time;
+        Ok(())
     }
 
-    pub fn record_swap_as_taker(&mut self, volume: u64, fee: u64) {
-        self.swaps_executed = self.swaps_executed.saturating_add(1);
-        self.volume_as_taker = self.volume_as_taker.saturating_add(volume as u128);
-        self.total_fees_paid = self.total_fees_paid.saturating_add(fee);
+    pub fn record_swap_as_taker(&mut self, volume: u64, fee: u64, current_time: i64) -> Result<()> {
+        self.swaps_executed = self
+            .swaps_executed
+            .checked_add(1)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.volume_as_taker = self
+            .volume_as_taker
+            .checked_add(volume as u128)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.total_fees_paid = self
+            .total_fees_paid
+            .checked_add(fee)
+            .ok_or(SelixError::ArithmeticOverflow)?;
+        self.last_activity_at = current_time;
+        Ok(())
     }
 }
@@ -40,6 +40,118 @@ pub fn calculate_partial_amounts(
     Ok((partial_source, destination))
 }
 
+/// Calculate the Dutch auction amount at `current_time`
+/// Decays from `start_amount` at `start_time` to `floor_amount` at `end_time`,
+/// linearly or in steps of `step_seconds` when non-zero
+pub fn calculate_dutch_auction_amount(
+    start_amount: u64,
+    floor_amount: u64,
+    start_time: i64,
+    end_time: i64,
+    step_seconds: i64,
+    current_time: i64,
+) -> Result<u64> {
+    if current_time <= start_time {
+        return Ok(start_amount);
+    }
+    if current_time >= end_time {
+        return Ok(floor_amount);
+    }
+
+    let duration = end_time
+        .checked_sub(start_time)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+    let mut elapsed = current_time
+        .checked_sub(start_time)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+    if step_seconds > 0 {
+        elapsed -= elapsed % step_seconds;
+    }
+
+    let decay = (start_amount.saturating_sub(floor_amount) as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(SelixError::ArithmeticOverflow)?
+        .checked_div(duration as u128)
+        .ok_or(SelixError::DivisionByZero)?;
+
+    let decay = u64::try_from(decay).map_err(|_| error!(SelixError::ArithmeticOverflow))?;
+
+    start_amount
+        .checked_sub(decay)
+        .ok_or_else(|| error!(SelixError::ArithmeticUnderflow))
+}
+
+/// Calculate the amount unlocked by a release schedule at `current_time`
+/// Nothing unlocks before `cliff_time`, then `total` unlocks linearly
+/// from `start_time` until `end_time`
+pub fn calculate_unlocked_amount(
+    total: u64,
+    start_time: i64,
+    cliff_time: i64,
+    end_time: i64,
+    current_time: i64,
+) -> Result<u64> {
+    if current_time < cliff_time {
+        return Ok(0);
+    }
+    if current_time >= end_time {
+        return Ok(total);
+    }
+
+    let unlocked = (total as u128)
+        .checked_mul(current_time.saturating_sub(start_time) as u128)
+        .ok_or(SelixError::ArithmeticOverflow)?
+        .checked_div(end_time.saturating_sub(start_time) as u128)
+        .ok_or(SelixError::DivisionByZero)?;
+
+    u64::try_from(unlocked).map_err(|_| error!(SelixError::ArithmeticOverflow))
+}
+
+/// Calculate the destination amount for `amount_source` at an oracle price
+/// `price * 10^exponent` is whole destination tokens per whole source token;
+/// the result is scaled by mint decimals and a signed premium in basis points
+pub fn calculate_oracle_amount(
+    amount_source: u64,
+    price: i64,
+    exponent: i32,
+    source_decimals: u8,
+    destination_decimals: u8,
+    premium_bps: i16,
+) -> Result<u64> {
+    require!(price > 0, SelixError::InvalidPriceFeed);
+    let price = price as u128;
+    let premium_factor =
+        u128::try_from(crate::constants::BPS_DENOMINATOR as i64 + premium_bps as i64)
+            .map_err(|_| error!(SelixError::InvalidCalculation))?;
+
+    let mut amount = (amount_source as u128)
+        .checked_mul(price)
+        .ok_or(SelixError::ArithmeticOverflow)?
+        .checked_mul(premium_factor)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+
+    let scale = exponent
+        .checked_add(destination_decimals as i32)
+        .and_then(|scale| scale.checked_sub(source_decimals as i32))
+        .ok_or(SelixError::ArithmeticOverflow)?;
+    let factor = 10u128
+        .checked_pow(scale.unsigned_abs())
+        .ok_or(SelixError::ArithmeticOverflow)?;
+    amount = if scale >= 0 {
+        amount
+            .checked_mul(factor)
+            .ok_or(SelixError::ArithmeticOverflow)?
+    } else {
+        amount / factor
+    };
+
+    let amount = amount
+        .checked_div(crate::constants::BPS_DENOMINATOR as u128)
+        .ok_or(SelixError::DivisionByZero)?;
+
+    u64::try_from(amount).map_err(|_| error!(SelixError::ArithmeticOverflow))
+}
+
 /// Calculate exchange rate (destination per source)
 /// Returns rate in basis points for precision
 pub fn calculate_rate(source_amount: u64, destination_amount: u64) -> Result<u64> {
@@ -56,6 +168,89 @@ pub fn calculate_rate(source_amount: u64, destination_amount: u64) -> Result<u64
     u64::try_from(rate).map_err(|_| error!(SelixError::ArithmeticOverflow))
 }
 
+/// Settle listing A (sells token A for B) against listing B (sells B for A).
+/// Fills as much as both listings allow at each maker's own price and
+/// returns (amount_a, amount_b, amount_b_to_maker_a); the rest of
+/// `amount_b` is the surplus between the two prices
+pub fn calculate_match_amounts(
+    a_source_remaining: u64,
+    a_destination_remaining: u64,
+    a_max_fill: u64,
+    b_source_remaining: u64,
+    b_destination_remaining: u64,
+    b_max_fill: u64,
+) -> Result<(u64, u64, u64)> {
+    // A asks no more B per A than B offers
+    let a_ask = (a_destination_remaining as u128)
+        .checked_mul(b_destination_remaining as u128)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+    let b_bid = (a_source_remaining as u128)
+        .checked_mul(b_source_remaining as u128)
+        .ok_or(SelixError::ArithmeticOverflow)?;
+    require!(a_ask <= b_bid, SelixError::ListingsDoNotCross);
+
+    // A tokens listing B asks for its largest fill
+    let (_, b_wants_a) =
+        calculate_partial_amounts(b_source_remaining, b_destination_remaining, b_max_fill)?;
+
+    let (amount_a, amount_b) = if a_max_fill >= b_wants_a {
+        (b_wants_a, b_max_fill)
+    } else {
+        let (_, amount_b) =
+            calculate_partial_amounts(b_destination_remaining, b_source_remaining, a_max_fill)?;
+        (a_max_fill, amount_b)
+    };
+
+    let (_, amount_b_to_maker_a) =
+        calculate_partial_amounts(a_source_remaining, a_destination_remaining, amount_a)?;
+
+    require!(
+        amount_a > 0 && amount_b_to_maker_a > 0,
+        SelixError::InvalidAmount
+    );
+    require!(
+        amount_b >= amount_b_to_maker_a,
+        SelixError::ListingsDoNotCross
+    );
+
+    Ok((amount_a, amount_b, amount_b_to_maker_a))
+}
+
+/// Size a fill that pays at most `budget` destination tokens, capped at
+/// `max_fill` source tokens. Returns (amount_source, amount_destination,
+/// unspent); `unspent` is the part of `budget` the fill does not pay
+pub fn calculate_budget_fill(
+    source_remaining: u64,
+    destination_remaining: u64,
+    max_fill: u64,
+    budget: u64,
+) -> Result<(u64, u64, u64)> {
+    let (_, affordable_source) =
+        calculate_partial_amounts(destination_remaining, source_remaining, budget)?;
+    let amount_source = affordable_source.min(max_fill);
+
+    let (_, amount_destination) =
+        calculate_partial_amounts(source_remaining, destination_remaining, amount_source)?;
+    let unspent = budget
+        .checked_sub(amount_destination)
+        .ok_or(SelixError::ArithmeticUnderflow)?;
+
+    Ok((amount_source, amount_destination, unspent))
+}
+
+/// Compare the prices (destination per source) of two amount pairs
+pub fn compare_prices(
+    a_source: u64,
+    a_destination: u64,
+    b_source: u64,
+    b_destination: u64,
+) -> std::cmp::Ordering {
+    // u64 * u64 always fits in u128
+    let a_price = a_destination as u128 * b_source as u128;
+    let b_price = b_destination as u128 * a_source as u128;
+    a_price.cmp(&b_price)
+}
+
 /// Check if rate is within slippage tolerance
 pub fn check_slippage(expected_rate: u64, actual_rate: u64, max_slippage_bps: u16) -> Result<bool> {
     let max_deviation = (expected_rate as u128)
@@ -103,6 +298,81 @@ mod tests {
         assert_eq!(dest, 1000);
     }
 
+    #[test]
+    fn test_calculate_dutch_auction_amount() {
+        // Linear decay from 2000 to 1000 over 100 seconds
+        assert_eq!(
+            calculate_dutch_auction_amount(2000, 1000, 0, 100, 0, 0).unwrap(),
+            2000
+        );
+        assert_eq!(
+            calculate_dutch_auction_amount(2000, 1000, 0, 100, 0, 25).unwrap(),
+            1750
+        );
+        assert_eq!(
+            calculate_dutch_auction_amount(2000, 1000, 0, 100, 0, 100).unwrap(),
+            1000
+        );
+        assert_eq!(
+            calculate_dutch_auction_amount(2000, 1000, 0, 100, 0, 150).unwrap(),
+            1000
+        );
+
+        // Stepped decay holds the price until the next 20 second step
+        assert_eq!(
+            calculate_dutch_auction_amount(2000, 1000, 0, 100, 20, 39).unwrap(),
+            1800
+        );
+        assert_eq!(
+            calculate_dutch_auction_amount(2000, 1000, 0, 100, 20, 40).unwrap(),
+            1600
+        );
+    }
+
+    #[test]
+    fn test_calculate_unlocked_amount() {
+        // Linear release of 1000 over 100 seconds with a 25 second cliff
+        assert_eq!(calculate_unlocked_amount(1000, 0, 25, 100, 10).unwrap(), 0);
+        assert_eq!(
+            calculate_unlocked_amount(1000, 0, 25, 100, 25).unwrap(),
+            250
+        );
+        assert_eq!(
+            calculate_unlocked_amount(1000, 0, 25, 100, 50).unwrap(),
+            500
+        );
+        assert_eq!(
+            calculate_unlocked_amount(1000, 0, 25, 100, 200).unwrap(),
+            1000
+        );
+
+        // Pure cliff releases everything at once
+        assert_eq!(calculate_unlocked_amount(1000, 0, 100, 100, 99).unwrap(), 0);
+        assert_eq!(
+            calculate_unlocked_amount(1000, 0, 100, 100, 100).unwrap(),
+            1000
+        );
+    }
+
+    #[test]
+    fn test_calculate_oracle_amount() {
+        // 2 SOL (9 decimals) at $150.00000000 (expo -8) into USDC (6 decimals)
+        let amount = calculate_oracle_amount(2_000_000_000, 15_000_000_000, -8, 9, 6, 0).unwrap();
+        assert_eq!(amount, 300_000_000);
+
+        // 1% premium and 1% discount
+        let premium =
+            calculate_oracle_amount(2_000_000_000, 15_000_000_000, -8, 9, 6, 100).unwrap();
+        assert_eq!(premium, 303_000_000);
+        let discount =
+            calculate_oracle_amount(2_000_000_000, 15_000_000_000, -8, 9, 6, -100).unwrap();
+        assert_eq!(discount, 297_000_000);
+
+        // Non-positive prices are rejected
+        assert!(calculate_oracle_amount(1000, 0, -8, 6, 6, 0).is_err());
+        assert!(calculate_oracle_amount(1000, -1, -8, 6, 6, 0).is_err());
+    }
+
     #[test]
     fn test_calculate_rate() {
         // 2000 destination / 1000 source = 2.0 rate (20000 in bps)
@@ -120,4 +390,74 @@ mod tests {
         let not_ok = check_slippage(20000, 21000, 100).unwrap();
         assert!(!not_ok);
     }
+
+    #[test]
+    fn test_calculate_match_amounts() {
+        // A sells 100 A at 2 B each, B buys 100 A at 3 B each
+        assert_eq!(
+            calculate_match_amounts(100, 200, 100, 300, 100, 300).unwrap(),
+            (100, 300, 200)
+        );
+
+        // Listing A limits the fill
+        assert_eq!(
+            calculate_match_amounts(100, 200, 50, 300, 100, 300).unwrap(),
+            (50, 150, 100)
+        );
+
+        // Listing B limits the fill
+        assert_eq!(
+            calculate_match_amounts(100, 200, 100, 300, 100, 60).unwrap(),
+            (20, 60, 40)
+        );
+
+        // Equal prices leave no surplus
+        assert_eq!(
+            calculate_match_amounts(100, 200, 100, 200, 100, 200).unwrap(),
+            (100, 200, 200)
+        );
+
+        // B pays less per A than A asks
+        assert!(calculate_match_amounts(100, 200, 100, 150, 100, 150).is_err());
+    }
+
+    #[test]
+    fn test_compare_prices() {
+        use std::cmp::Ordering;
+
+        // 2 per unit vs 3 per unit
+        assert_eq!(compare_prices(100, 200, 100, 300), Ordering::Less);
+        assert_eq!(compare_prices(100, 300, 100, 200), Ordering::Greater);
+        // Same price at different sizes
+        assert_eq!(compare_prices(100, 200, 7, 14), Ordering::Equal);
+        // No overflow at the u64 limit
+        assert_eq!(compare_prices(u64::MAX, u64::MAX, 1, 2), Ordering::Less);
+    }
+
+    #[test]
+    fn test_calculate_budget_fill() {
+        // 1000 source for 2000 destination, budget spent in full
+        assert_eq!(
+            calculate_budget_fill(1000, 2000, 1000, 500).unwrap(),
+            (250, 500, 0)
+        );
+
+        // Capped by the largest fill: the rest of the budget is unspent
+        assert_eq!(
+            calculate_budget_fill(1000, 2000, 100, 500).unwrap(),
+            (100, 200, 300)
+        );
+
+        // Rounding dust stays unspent
+        assert_eq!(
+            calculate_budget_fill(1000, 3000, 1000, 10).unwrap(),
+            (3, 9, 1)
+        );
+
+        // Budget larger than the listing
+        assert_eq!(
+            calculate_budget_fill(1000, 2000, 1000, 5000).unwrap(),
+            (1000, 2000, 3000)
+        );
+    }
 }
@@ -0,0 +1,192 @@
+use crate::errors::SelixError;
+use anchor_lang::prelude::*;
+
+/// Metaplex Token Metadata program
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Seed prefix of Metaplex metadata PDAs
+const METADATA_SEED: &[u8] = b"metadata";
+
+/// Account key of a Metaplex `MetadataV1` account
+const METADATA_V1_KEY: u8 = 4;
+
+/// Size of a Metaplex `Creator` (address, verified, share)
+const CREATOR_SIZE: usize = 34;
+
+/// Collection fields of Metaplex metadata
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NftMetadata {
+    pub mint: Pubkey,
+    /// Collection mint and whether the collection authority verified it
+    pub collection: Option<(Pubkey, bool)>,
+}
+
+fn take<'a>(data: &'a [u8], offset: &mut usize, len: usize) -> Result<&'a [u8]> {
+    let bytes = data
+        .get(*offset..*offset + len)
+        .ok_or(SelixError::InvalidNftMetadata)?;
+    *offset += len;
+    Ok(bytes)
+}
+
+fn take_u8(data: &[u8], offset: &mut usize) -> Result<u8> {
+    Ok(take(data, offset, 1)?[0])
+}
+
+fn skip_string(data: &[u8], offset: &mut usize) -> Result<()> {
+    let len = u32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap());
+    take(data, offset, len as usize)?;
+    Ok(())
+}
+
+/// Parse the mint and collection of a Metaplex `MetadataV1` account
+///
+/// Layout: key, update authority, mint, name, symbol, uri, seller fee,
+/// creators, primary sale, mutable, edition nonce, token standard and
+/// collection, followed by fields Selix does not read
+pub fn parse_nft_metadata(data: &[u8]) -> Result<NftMetadata> {
+    let mut offset = 0;
+
+    require!(
+        take_u8(data, &mut offset)? == METADATA_V1_KEY,
+        SelixError::InvalidNftMetadata
+    );
+
+    // Update authority
+    take(data, &mut offset, 32)?;
+    let mint = Pubkey::try_from(take(data, &mut offset, 32)?).unwrap();
+
+    // Name, symbol, uri and seller fee
+    skip_string(data, &mut offset)?;
+    skip_string(data, &mut offset)?;
+    skip_string(data, &mut offset)?;
+    take(data, &mut offset, 2)?;
+
+    // Creators
+    if take_u8(data, &mut offset)? == 1 {
+        let count = u32::from_le_bytes(take(data, &mut offset, 4)?.try_into().unwrap());
+        take(data, &mut offset, count as usize * CREATOR_SIZE)?;
+    }
+
+    // Primary sale happened and is mutable
+    take(data, &mut offset, 2)?;
+
+    // Edition nonce and token standard
+    for _ in 0..2 {
+        if take_u8(data, &mut offset)? == 1 {
+            take(data, &mut offset, 1)?;
+        }
+    }
+
+    let collection = if take_u8(data, &mut offset)? == 1 {
+        let verified = take_u8(data, &mut offset)? == 1;
+        let key = Pubkey::try_from(take(data, &mut offset, 32)?).unwrap();
+        Some((key, verified))
+    } else {
+        None
+    };
+
+    Ok(NftMetadata { mint, collection })
+}
+
+/// Validate `mint` is a verified member of `collection` through its
+/// Metaplex metadata account
+pub fn validate_collection_nft(
+    metadata: &AccountInfo,
+    mint: &Pubkey,
+    collection: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        *metadata.owner,
+        TOKEN_METADATA_PROGRAM_ID,
+        SelixError::InvalidNftMetadata
+    );
+
+    let (expected_pda, _) = Pubkey::find_program_address(
+        &[
+            METADATA_SEED,
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+    require_keys_eq!(metadata.key(), expected_pda, SelixError::InvalidNftMetadata);
+
+    let parsed = parse_nft_metadata(&metadata.try_borrow_data()?)?;
+    require_keys_eq!(parsed.mint, *mint, SelixError::InvalidNftMetadata);
+    require!(
+        parsed.collection == Some((*collection, true)),
+        SelixError::NftNotInCollection
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn borsh_string(data: &mut Vec<u8>, value: &str) {
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value.as_bytes());
+    }
+
+    fn metadata_data(mint: &Pubkey, creators: u32, collection: Option<(Pubkey, bool)>) -> Vec<u8> {
+        let mut data = vec![METADATA_V1_KEY];
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(mint.as_ref());
+        borsh_string(&mut data, "Selix #1\0\0\0");
+        borsh_string(&mut data, "SLX");
+        borsh_string(&mut data, "https://example.com/1.json");
+        data.extend_from_slice(&500u16.to_le_bytes());
+        if creators > 0 {
+            data.push(1);
+            data.extend_from_slice(&creators.to_le_bytes());
+            data.extend(std::iter::repeat_n(7u8, creators as usize * CREATOR_SIZE));
+        } else {
+            data.push(0);
+        }
+        data.extend_from_slice(&[1, 1]);
+        data.extend_from_slice(&[1, 255]);
+        data.extend_from_slice(&[1, 0]);
+        match collection {
+            Some((key, verified)) => {
+                data.extend_from_slice(&[1, verified as u8]);
+                data.extend_from_slice(key.as_ref());
+            }
+            None => data.push(0),
+        }
+        data.extend_from_slice(&[0u8; 16]);
+        data
+    }
+
+    #[test]
+    fn test_parse_nft_metadata() {
+        let mint = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+
+        let data = metadata_data(&mint, 2, Some((collection, true)));
+        let parsed = parse_nft_metadata(&data).unwrap();
+        assert_eq!(parsed.mint, mint);
+        assert_eq!(parsed.collection, Some((collection, true)));
+
+        let data = metadata_data(&mint, 0, Some((collection, false)));
+        assert_eq!(
+            parse_nft_metadata(&data).unwrap().collection,
+            Some((collection, false))
+        );
+
+        let data = metadata_data(&mint, 1, None);
+        assert_eq!(parse_nft_metadata(&data).unwrap().collection, None);
+
+        // Wrong account key
+        let mut data = metadata_data(&mint, 0, None);
+        data[0] = 6;
+        assert!(parse_nft_metadata(&data).is_err());
+
+        // Truncated
+        let data = metadata_data(&mint, 3, Some((collection, true)));
+        assert!(parse_nft_metadata(&data[..100]).is_err());
+    }
+}
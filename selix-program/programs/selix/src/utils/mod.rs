@@ -1,11 +1,19 @@
 pub mod math;
+pub mod metadata;
+pub mod nonce;
+pub mod oracle;
 pub mod pda;
+pub mod signature;
 pub mod time;
 pub mod token;
 pub mod validation;
 
 pub use math::*;
+pub use metadata::*;
+pub use nonce::*;
+pub use oracle::*;
 pub use pda::*;
+pub use signature::*;
 pub use time::*;
 pub use token::*;
 pub use validation::*;
@@ -0,0 +1,125 @@
+use crate::{constants::ORDER_NONCE_BITMAP_WORDS, errors::SelixError};
+use anchor_lang::prelude::*;
+
+/// Number of nonces tracked above the floor, one bit each
+pub const ORDER_NONCE_WINDOW: u64 = ORDER_NONCE_BITMAP_WORDS as u64 * 64;
+
+/// Bitmap word and bit of a nonce above the floor
+fn nonce_bit(nonce_floor: u64, nonce: u64) -> Result<(usize, u64)> {
+    require!(nonce >= nonce_floor, SelixError::NonceAlreadyUsed);
+    let offset = nonce - nonce_floor;
+    require!(offset < ORDER_NONCE_WINDOW, SelixError::NonceOutOfRange);
+
+    Ok(((offset / 64) as usize, 1u64 << (offset % 64)))
+}
+
+/// Mark a nonce used, failing if it was already used or cancelled
+pub fn use_nonce(nonce_floor: u64, bitmap: &mut [u64], nonce: u64) -> Result<()> {
+    let (word, bit) = nonce_bit(nonce_floor, nonce)?;
+    require!(bitmap[word] & bit == 0, SelixError::NonceAlreadyUsed);
+    bitmap[word] |= bit;
+    Ok(())
+}
+
+/// Invalidate every nonce below `new_floor` and slide the bitmap window up
+pub fn raise_nonce_floor(nonce_floor: &mut u64, bitmap: &mut [u64], new_floor: u64) -> Result<()> {
+    require!(new_floor > *nonce_floor, SelixError::InvalidNonceFloor);
+
+    let shift = (new_floor - *nonce_floor).min(ORDER_NONCE_WINDOW);
+    let word_shift = (shift / 64) as usize;
+    let bit_shift = (shift % 64) as u32;
+
+    for i in 0..bitmap.len() {
+        let low = bitmap.get(i + word_shift).copied().unwrap_or(0);
+        let high = bitmap.get(i + word_shift + 1).copied().unwrap_or(0);
+        bitmap[i] = if bit_shift == 0 {
+            low
+        } else {
+            (low >> bit_shift) | (high << (64 - bit_shift))
+        };
+    }
+
+    *nonce_floor = new_floor;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use_nonce() {
+        let mut bitmap = [0u64; ORDER_NONCE_BITMAP_WORDS];
+        assert!(use_nonce(10, &mut bitmap, 10).is_ok());
+        assert!(use_nonce(10, &mut bitmap, 75).is_ok());
+
+        // Replay
+        assert!(use_nonce(10, &mut bitmap, 75).is_err());
+
+        // Below the floor
+        assert!(use_nonce(10, &mut bitmap, 9).is_err());
+
+        // Beyond the window
+        assert!(use_nonce(10, &mut bitmap, 10 + ORDER_NONCE_WINDOW).is_err());
+        assert!(use_nonce(10, &mut bitmap, 10 + ORDER_NONCE_WINDOW - 1).is_ok());
+    }
+
+    #[test]
+    fn test_nonce_window_boundaries() {
+        assert_eq!(ORDER_NONCE_WINDOW, 1024);
+        let floor = 5_000;
+        let mut bitmap = [0u64; ORDER_NONCE_BITMAP_WORDS];
+        let used: Error = SelixError::NonceAlreadyUsed.into();
+        let out_of_range: Error = SelixError::NonceOutOfRange.into();
+
+        // Just below the floor counts as used, the floor itself is free
+        assert_eq!(use_nonce(floor, &mut bitmap, floor - 1).unwrap_err(), used);
+        assert!(use_nonce(floor, &mut bitmap, floor).is_ok());
+
+        // First and last bit of the window, then one past it
+        assert!(use_nonce(floor, &mut bitmap, floor + 1023).is_ok());
+        assert_eq!(bitmap[ORDER_NONCE_BITMAP_WORDS - 1], 1 << 63);
+        assert_eq!(
+            use_nonce(floor, &mut bitmap, floor + 1024).unwrap_err(),
+            out_of_range
+        );
+        assert_eq!(
+            use_nonce(floor, &mut bitmap, u64::MAX).unwrap_err(),
+            out_of_range
+        );
+
+        // Raising the floor by one brings floor+1024 into the window and
+        // keeps the last used nonce at the top
+        let mut new_floor = floor;
+        raise_nonce_floor(&mut new_floor, &mut bitmap, floor + 1).unwrap();
+        assert_eq!(bitmap[ORDER_NONCE_BITMAP_WORDS - 1], 1 << 62);
+        assert_eq!(use_nonce(new_floor, &mut bitmap, floor).unwrap_err(), used);
+        assert_eq!(
+            use_nonce(new_floor, &mut bitmap, floor + 1023).unwrap_err(),
+            used
+        );
+        assert!(use_nonce(new_floor, &mut bitmap, floor + 1024).is_ok());
+    }
+
+    #[test]
+    fn test_raise_nonce_floor() {
+        let mut floor = 0;
+        let mut bitmap = [0u64; ORDER_NONCE_BITMAP_WORDS];
+        use_nonce(floor, &mut bitmap, 3).unwrap();
+        use_nonce(floor, &mut bitmap, 100).unwrap();
+
+        // Used nonces above the new floor stay used
+        raise_nonce_floor(&mut floor, &mut bitmap, 70).unwrap();
+        assert_eq!(floor, 70);
+        assert!(use_nonce(floor, &mut bitmap, 100).is_err());
+        assert!(use_nonce(floor, &mut bitmap, 3).is_err());
+        assert!(use_nonce(floor, &mut bitmap, 99).is_ok());
+
+        // Floor can only move up
+        assert!(raise_nonce_floor(&mut floor, &mut bitmap, 70).is_err());
+
+        // A jump past the window clears the bitmap
+        raise_nonce_floor(&mut floor, &mut bitmap, 70 + 5 * ORDER_NONCE_WINDOW).unwrap();
+        assert!(bitmap.iter().all(|word| *word == 0));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{constants::*, errors::SelixError, state::TriggerCondition};
+use anchor_lang::prelude::*;
+
+/// Pyth receiver program that owns `PriceUpdateV2` accounts
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Anchor discriminator of the Pyth `PriceUpdateV2` account
+pub const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Borsh variant index of `VerificationLevel::Full`
+const VERIFICATION_LEVEL_FULL: u8 = 1;
+
+/// Price reading from a Pyth-style price feed
+/// The price is `price * 10^exponent`, with `conf` in the same units
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: &mut usize) -> Result<[u8; N]> {
+    let bytes = data
+        .get(*offset..*offset + N)
+        .ok_or(SelixError::InvalidPriceFeed)?;
+    *offset += N;
+    Ok(bytes.try_into().unwrap())
+}
+
+/// Parse a fully verified Pyth `PriceUpdateV2` account
+///
+/// Layout: discriminator (8), write authority (32), verification level (1 for
+/// `Full`), feed id (32), price (i64), conf (u64), exponent (i32),
+/// publish time (i64), followed by fields Selix does not read
+/// Non-positive prices are rejected
+pub fn parse_price_update(data: &[u8]) -> Result<OraclePrice> {
+    let mut offset = 0;
+
+    let discriminator = read_bytes::<8>(data, &mut offset)?;
+    require!(
+        discriminator == PRICE_UPDATE_V2_DISCRIMINATOR,
+        SelixError::InvalidPriceFeed
+    );
+
+    // Write authority
+    offset += 32;
+
+    // Partially verified updates are rejected
+    let [verification_level] = read_bytes::<1>(data, &mut offset)?;
+    require!(
+        verification_level == VERIFICATION_LEVEL_FULL,
+        SelixError::InvalidPriceFeed
+    );
+
+    let price = OraclePrice {
+        feed_id: read_bytes(data, &mut offset)?,
+        price: i64::from_le_bytes(read_bytes(data, &mut offset)?),
+        conf: u64::from_le_bytes(read_bytes(data, &mut offset)?),
+        exponent: i32::from_le_bytes(read_bytes(data, &mut offset)?),
+        publish_time: i64::from_le_bytes(read_bytes(data, &mut offset)?),
+    };
+    require!(price.price > 0, SelixError::InvalidPriceFeed);
+
+    Ok(price)
+}
+
+/// Validate an oracle price is positive, fresh and precise enough
+pub fn validate_oracle_price(price: &OraclePrice, current_time: i64) -> Result<()> {
+    require!(price.price > 0, SelixError::InvalidPriceFeed);
+    require!(
+        current_time.saturating_sub(price.publish_time) <= MAX_ORACLE_PRICE_AGE_SECONDS,
+        SelixError::StalePrice
+    );
+
+    let confidence_bps = (price.conf as u128)
+        .checked_mul(BPS_DENOMINATOR as u128)
+        .ok_or(SelixError::ArithmeticOverflow)?
+        .checked_div(price.price as u128)
+        .ok_or(SelixError::DivisionByZero)?;
+    require!(
+        confidence_bps <= MAX_ORACLE_CONFIDENCE_BPS as u128,
+        SelixError::PriceConfidenceTooWide
+    );
+
+    Ok(())
+}
+
+/// Load and validate the price from a Pyth `PriceUpdateV2` account
+/// Any receiver-owned account can hold a fresh price for any asset, so
+/// consumers pass the feed id recorded on the listing (`None` on creation)
+pub fn load_oracle_price(
+    price_feed: &AccountInfo,
+    expected_feed_id: Option<&[u8; 32]>,
+    current_time: i64,
+) -> Result<OraclePrice> {
+    require_keys_eq!(
+        *price_feed.owner,
+        PYTH_RECEIVER_PROGRAM_ID,
+        SelixError::InvalidPriceFeed
+    );
+
+    let price = parse_price_update(&price_feed.try_borrow_data()?)?;
+    if let Some(feed_id) = expected_feed_id {
+        require!(price.feed_id == *feed_id, SelixError::InvalidPriceFeed);
+    }
+    validate_oracle_price(&price, current_time)?;
+
+    Ok(price)
+}
+
+/// Check a raw oracle price against a trigger price in the same units
+pub fn is_trigger_met(condition: TriggerCondition, trigger_price: i64, price: i64) -> bool {
+    match condition {
+        TriggerCondition::None => true,
+        TriggerCondition::AtOrBelow => price <= trigger_price,
+        TriggerCondition::AtOrAbove => price >= trigger_price,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price_update_data(
+        verification_level: &[u8],
+        price: i64,
+        conf: u64,
+        exponent: i32,
+    ) -> Vec<u8> {
+        let mut data = PRICE_UPDATE_V2_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[0u8; 32]);
+        data.extend_from_slice(verification_level);
+        data.extend_from_slice(&[7u8; 32]);
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&conf.to_le_bytes());
+        data.extend_from_slice(&exponent.to_le_bytes());
+        data.extend_from_slice(&1_000i64.to_le_bytes());
+        data.extend_from_slice(&999i64.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_parse_price_update() {
+        let data = price_update_data(&[VERIFICATION_LEVEL_FULL], 15_000_000_000, 10_000_000, -8);
+        let price = parse_price_update(&data).unwrap();
+        assert_eq!(price.feed_id, [7u8; 32]);
+        assert_eq!(price.price, 15_000_000_000);
+        assert_eq!(price.conf, 10_000_000);
+        assert_eq!(price.exponent, -8);
+        assert_eq!(price.publish_time, 1_000);
+
+        // Partially verified update
+        let data = price_update_data(&[0, 3], 15_000_000_000, 10_000_000, -8);
+        assert!(parse_price_update(&data).is_err());
+
+        // Wrong discriminator
+        let mut data = price_update_data(&[VERIFICATION_LEVEL_FULL], 1, 0, 0);
+        data[0] ^= 1;
+        assert!(parse_price_update(&data).is_err());
+
+        // Non-positive price
+        let data = price_update_data(&[VERIFICATION_LEVEL_FULL], 0, 0, -8);
+        assert!(parse_price_update(&data).is_err());
+        let data = price_update_data(&[VERIFICATION_LEVEL_FULL], -1, 0, -8);
+        assert!(parse_price_update(&data).is_err());
+
+        // Truncated account
+        let data = price_update_data(&[VERIFICATION_LEVEL_FULL], 1, 0, 0);
+        assert!(parse_price_update(&data[..60]).is_err());
+    }
+
+    #[test]
+    fn test_validate_oracle_price() {
+        let price = OraclePrice {
+            feed_id: [7u8; 32],
+            price: 15_000_000_000,
+            conf: 10_000_000,
+            exponent: -8,
+            publish_time: 1_000,
+        };
+        assert!(validate_oracle_price(&price, 1_010).is_ok());
+
+        // Stale
+        assert!(validate_oracle_price(&price, 1_000 + MAX_ORACLE_PRICE_AGE_SECONDS + 1).is_err());
+
+        // Confidence interval too wide
+        let wide = OraclePrice {
+            conf: 1_500_000_000,
+            ..price
+        };
+        assert!(validate_oracle_price(&wide, 1_010).is_err());
+
+        // Non-positive price
+        let negative = OraclePrice { price: -1, ..price };
+        assert!(validate_oracle_price(&negative, 1_010).is_err());
+    }
+
+    #[test]
+    fn test_is_trigger_met() {
+        // Stop-loss at 100
+        assert!(!is_trigger_met(TriggerCondition::AtOrBelow, 100, 101));
+        assert!(is_trigger_met(TriggerCondition::AtOrBelow, 100, 100));
+        assert!(is_trigger_met(TriggerCondition::AtOrBelow, 100, 99));
+
+        // Take-profit at 100
+        assert!(!is_trigger_met(TriggerCondition::AtOrAbove, 100, 99));
+        assert!(is_trigger_met(TriggerCondition::AtOrAbove, 100, 100));
+        assert!(is_trigger_met(TriggerCondition::AtOrAbove, 100, 101));
+
+        // No trigger
+        assert!(is_trigger_met(TriggerCondition::None, 100, 1));
+    }
+}
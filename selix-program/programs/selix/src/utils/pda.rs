@@ -1,5 +1,6 @@
-use crate::constants::*;
+use crate::{constants::*, errors::SelixError};
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 
 /// Derive platform PDA
 pub fn derive_platform_pda(authority: &Pubkey) -> (Pubkey, u8) {
@@ -29,6 +30,133 @@ pub fn derive_whitelist_pda(mint: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[WHITELIST_SEED, mint.as_ref()], &crate::ID)
 }
 
+/// Derive a maker's signed order nonces PDA
+pub fn derive_order_nonces_pda(maker: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ORDER_NONCES_SEED, maker.as_ref()], &crate::ID)
+}
+
+/// Derive the delegate PDA makers approve for signed order settlement
+pub fn derive_order_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ORDER_AUTHORITY_SEED], &crate::ID)
+}
+
+/// Create a program-owned PDA account, topping up an address that was
+/// already sent lamports
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+    system_program: &Program<'info, System>,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            program_id,
+        );
+    }
+
+    let top_up = rent.saturating_sub(current_lamports);
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        program_id,
+    )
+}
+
+/// Grow a program-owned account created under an older, shorter layout.
+/// Fields are only ever appended, so the zeroed tail reads as their defaults
+pub fn grow_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if account.data_len() >= space {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    account.resize(space)?;
+    Ok(())
+}
+
+/// Check an account that may still use an older layout: it must be owned by
+/// the program, carry `discriminator` and store `owner_key` as its first field
+pub fn validate_legacy_account(
+    account: &AccountInfo,
+    discriminator: &[u8],
+    owner_key: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(*account.owner, crate::ID, SelixError::InvalidAccountOwner);
+
+    let data = account.try_borrow_data()?;
+    let header_len = discriminator.len() + 32;
+    require!(data.len() >= header_len, SelixError::AccountNotInitialized);
+    require!(
+        &data[..discriminator.len()] == discriminator,
+        SelixError::AccountNotInitialized
+    );
+    require!(
+        &data[discriminator.len()..header_len] == owner_key.as_ref(),
+        SelixError::UnauthorizedAuthority
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,4 +200,55 @@ mod tests {
         assert_eq!(pda, pda2);
         assert_eq!(bump, bump2);
     }
+
+    #[test]
+    fn test_validate_legacy_account() {
+        let key = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let discriminator = crate::state::UserProfile::DISCRIMINATOR;
+        let mut lamports = 1_000_000u64;
+        let mut data = [discriminator, user.as_ref(), &[0u8; 16]].concat();
+        let program_id = crate::ID;
+        let account = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        assert!(validate_legacy_account(&account, discriminator, &user).is_ok());
+
+        // Wrong stored owner
+        assert!(validate_legacy_account(&account, discriminator, &Pubkey::new_unique()).is_err());
+
+        // Wrong account type
+        let other = crate::state::Platform::DISCRIMINATOR;
+        assert!(validate_legacy_account(&account, other, &user).is_err());
+    }
+
+    #[test]
+    fn test_validate_legacy_account_rejects_foreign_owner() {
+        let key = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let discriminator = crate::state::UserProfile::DISCRIMINATOR;
+        let mut lamports = 1_000_000u64;
+        let mut data = [discriminator, user.as_ref()].concat();
+        let foreign_owner = Pubkey::new_unique();
+        let account = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &foreign_owner,
+            false,
+            0,
+        );
+
+        assert!(validate_legacy_account(&account, discriminator, &user).is_err());
+    }
 }
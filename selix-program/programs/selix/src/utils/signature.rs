@@ -0,0 +1,177 @@
+use crate::errors::SelixError;
+use anchor_lang::prelude::*;
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+
+/// Native program that verifies Ed25519 signatures
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// Offset of the first signature offsets block (count and padding bytes first)
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Size of one Ed25519 signature offsets block (seven u16 fields)
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+const SIGNATURE_SIZE: usize = 64;
+
+/// Instruction index meaning "this Ed25519 instruction"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Check Ed25519 program instruction data verifies a single signature by
+/// `signer` over exactly `message`, with all data inline in that instruction
+///
+/// The Ed25519 program fails the transaction on a bad signature, so only the
+/// signer and message it checked need to be matched here
+pub fn verify_ed25519_instruction_data(data: &[u8], signer: &Pubkey, message: &[u8]) -> Result<()> {
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE && data[0] == 1,
+        SelixError::InvalidSignatureInstruction
+    );
+
+    let read_u16 = |field: usize| {
+        let at = SIGNATURE_OFFSETS_START + field * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let signature_offset = read_u16(0) as usize;
+    let signature_instruction_index = read_u16(1);
+    let public_key_offset = read_u16(2) as usize;
+    let public_key_instruction_index = read_u16(3);
+    let message_data_offset = read_u16(4) as usize;
+    let message_data_size = read_u16(5) as usize;
+    let message_instruction_index = read_u16(6);
+
+    // Data referenced in other instructions could be swapped out
+    require!(
+        signature_instruction_index == CURRENT_INSTRUCTION
+            && public_key_instruction_index == CURRENT_INSTRUCTION
+            && message_instruction_index == CURRENT_INSTRUCTION,
+        SelixError::InvalidSignatureInstruction
+    );
+    require!(
+        data.get(signature_offset..signature_offset + SIGNATURE_SIZE)
+            .is_some(),
+        SelixError::InvalidSignatureInstruction
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(SelixError::InvalidSignatureInstruction)?;
+    require!(public_key == signer.as_ref(), SelixError::SignatureMismatch);
+
+    let signed_message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(SelixError::InvalidSignatureInstruction)?;
+    require!(signed_message == message, SelixError::SignatureMismatch);
+
+    Ok(())
+}
+
+/// Verify the instruction preceding the current one is an Ed25519 program
+/// instruction checking `signer`'s signature over `message`
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, SelixError::InvalidSignatureInstruction);
+
+    let instruction = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+    require_keys_eq!(
+        instruction.program_id,
+        ED25519_PROGRAM_ID,
+        SelixError::InvalidSignatureInstruction
+    );
+
+    verify_ed25519_instruction_data(&instruction.data, signer, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ed25519 instruction data laid out like the Solana SDK builds it
+    fn ed25519_data(signer: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let public_key_offset = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE;
+        let signature_offset = public_key_offset + 32;
+        let message_offset = signature_offset + SIGNATURE_SIZE;
+
+        let mut data = vec![1u8, 0];
+        for field in [
+            signature_offset as u16,
+            instruction_index,
+            public_key_offset as u16,
+            instruction_index,
+            message_offset as u16,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[9u8; SIGNATURE_SIZE]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_verify_ed25519_instruction_data() {
+        let signer = Pubkey::new_unique();
+        let message = b"selix order";
+        let data = ed25519_data(&signer, message, CURRENT_INSTRUCTION);
+        assert!(verify_ed25519_instruction_data(&data, &signer, message).is_ok());
+
+        // Different signer or message
+        assert!(verify_ed25519_instruction_data(&data, &Pubkey::new_unique(), message).is_err());
+        assert!(verify_ed25519_instruction_data(&data, &signer, b"selix order!").is_err());
+
+        // Data referenced from another instruction
+        let data = ed25519_data(&signer, message, 0);
+        assert!(verify_ed25519_instruction_data(&data, &signer, message).is_err());
+
+        // More than one signature
+        let mut data = ed25519_data(&signer, message, CURRENT_INSTRUCTION);
+        data[0] = 2;
+        assert!(verify_ed25519_instruction_data(&data, &signer, message).is_err());
+
+        // Truncated
+        let data = ed25519_data(&signer, message, CURRENT_INSTRUCTION);
+        assert!(
+            verify_ed25519_instruction_data(&data[..data.len() - 1], &signer, message).is_err()
+        );
+    }
+
+    #[test]
+    fn test_verify_ed25519_tampered_offsets() {
+        let signer = Pubkey::new_unique();
+        let message = b"selix order";
+        let tampered = |field: usize, value: usize| {
+            let mut data = ed25519_data(&signer, message, CURRENT_INSTRUCTION);
+            let at = SIGNATURE_OFFSETS_START + field * 2;
+            data[at..at + 2].copy_from_slice(&(value as u16).to_le_bytes());
+            verify_ed25519_instruction_data(&data, &signer, message)
+        };
+        let public_key_offset = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE;
+        let signature_offset = public_key_offset + 32;
+        let message_offset = signature_offset + SIGNATURE_SIZE;
+        let data_len = message_offset + message.len();
+        let mismatch: Error = SelixError::SignatureMismatch.into();
+        let invalid: Error = SelixError::InvalidSignatureInstruction.into();
+
+        // Signature outside the instruction data
+        assert_eq!(
+            tampered(0, data_len - SIGNATURE_SIZE + 1).unwrap_err(),
+            invalid
+        );
+
+        // Public key read from the signature bytes
+        assert_eq!(tampered(2, signature_offset).unwrap_err(), mismatch);
+        assert_eq!(tampered(2, data_len - 31).unwrap_err(), invalid);
+
+        // Message shifted, shortened or running past the data
+        assert_eq!(tampered(4, message_offset + 1).unwrap_err(), invalid);
+        assert_eq!(tampered(4, message_offset - 1).unwrap_err(), mismatch);
+        assert_eq!(tampered(5, message.len() - 1).unwrap_err(), mismatch);
+        assert_eq!(tampered(5, message.len() + 1).unwrap_err(), invalid);
+        assert_eq!(tampered(5, 0).unwrap_err(), mismatch);
+    }
+}
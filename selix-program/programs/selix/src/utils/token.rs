@@ -1,10 +1,33 @@
-use crate::errors::SelixError;
+use crate::{constants::*, errors::SelixError};
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::system_program;
+use anchor_spl::{
+    token::spl_token,
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            default_account_state::DefaultAccountState,
+            mint_close_authority::MintCloseAuthority,
+            non_transferable::NonTransferable,
+            permanent_delegate::PermanentDelegate,
+            transfer_fee::{
+                instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount, TransferFeeConfig,
+            },
+            transfer_hook, BaseStateWithExtensions, StateWithExtensions,
+        },
+        onchain::invoke_transfer_checked,
+        state::AccountState,
+    },
+    token_interface::{
+        sync_native, transfer_checked, Mint, SyncNative, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
 };
 
-/// Transfer tokens with checked decimals
+/// Transfer tokens with checked decimals.
+/// Transfer-hook mints resolve the hook's extra accounts from `remaining_accounts`.
+#[allow(clippy::too_many_arguments)]
 pub fn transfer_tokens<'info>(
     from: &InterfaceAccount<'info, TokenAccount>,
     to: &InterfaceAccount<'info, TokenAccount>,
@@ -13,7 +36,23 @@ pub fn transfer_tokens<'info>(
     token_program: &Interface<'info, TokenInterface>,
     amount: u64,
     signer_seeds: Option<&[&[&[u8]]]>,
+    remaining_accounts: &[AccountInfo<'info>],
 ) -> Result<()> {
+    if has_transfer_hook(mint)? {
+        invoke_transfer_checked(
+            &token_program.key(),
+            from.to_account_info(),
+            mint.to_account_info(),
+            to.to_account_info(),
+            authority.to_account_info(),
+            remaining_accounts,
+            amount,
+            mint.decimals,
+            signer_seeds.unwrap_or(&[]),
+        )?;
+        return Ok(());
+    }
+
     let cpi_accounts = TransferChecked {
         from: from.to_account_info(),
         to: to.to_account_info(),
@@ -53,6 +92,166 @@ pub fn close_token_account<'info>(
     anchor_spl::token_interface::close_account(cpi_context)
 }
 
+/// Risky features of a mint as a MINT_FEATURE_* bitmask
+pub fn mint_features(mint: &InterfaceAccount<Mint>) -> Result<u8> {
+    let mint_info = mint.to_account_info();
+    let data = mint_info.try_borrow_data()?;
+    mint_features_from_data(&data)
+}
+
+/// Risky features configured in raw mint data (base freeze authority and Token-2022 extensions)
+pub fn mint_features_from_data(data: &[u8]) -> Result<u8> {
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(data)
+        .map_err(|_| SelixError::InvalidTokenExtensionData)?;
+    let mut features = 0;
+
+    if mint.base.freeze_authority.is_some() {
+        features |= MINT_FEATURE_FREEZE_AUTHORITY;
+    }
+    if let Ok(extension) = mint.get_extension::<PermanentDelegate>() {
+        if Option::<Pubkey>::from(extension.delegate).is_some() {
+            features |= MINT_FEATURE_PERMANENT_DELEGATE;
+        }
+    }
+    if mint.get_extension::<NonTransferable>().is_ok() {
+        features |= MINT_FEATURE_NON_TRANSFERABLE;
+    }
+    if let Ok(extension) = mint.get_extension::<DefaultAccountState>() {
+        if extension.state == AccountState::Frozen as u8 {
+            features |= MINT_FEATURE_DEFAULT_FROZEN;
+        }
+    }
+    if let Ok(extension) = mint.get_extension::<MintCloseAuthority>() {
+        if Option::<Pubkey>::from(extension.close_authority).is_some() {
+            features |= MINT_FEATURE_CLOSE_AUTHORITY;
+        }
+    }
+
+    Ok(features)
+}
+
+/// Check if a mint carries the Token-2022 transfer-hook extension
+pub fn has_transfer_hook(mint: &InterfaceAccount<Mint>) -> Result<bool> {
+    let mint_info = mint.to_account_info();
+    let data = mint_info.try_borrow_data()?;
+    Ok(transfer_hook_program_from_mint_data(&data)?.is_some())
+}
+
+/// Transfer-hook program configured in raw mint data, if any
+pub fn transfer_hook_program_from_mint_data(data: &[u8]) -> Result<Option<Pubkey>> {
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(data)
+        .map_err(|_| SelixError::InvalidTokenExtensionData)?;
+
+    Ok(transfer_hook::get_program_id(&mint))
+}
+
+/// Token-2022 transfer fee withheld from `amount` at the current epoch
+pub fn calculate_transfer_fee(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let data = mint_info.try_borrow_data()?;
+    transfer_fee_from_mint_data(&data, Clock::get()?.epoch, amount)
+}
+
+/// Transfer fee configured in raw mint data (zero for mints without the extension)
+pub fn transfer_fee_from_mint_data(data: &[u8], epoch: u64, amount: u64) -> Result<u64> {
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(data)
+        .map_err(|_| SelixError::InvalidTokenExtensionData)?;
+
+    match mint.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(SelixError::ArithmeticOverflow)?),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Transfer fees withheld in raw token account data (zero without the extension)
+pub fn withheld_fees_from_account_data(data: &[u8]) -> Result<u64> {
+    let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(data)
+        .map_err(|_| SelixError::InvalidTokenExtensionData)?;
+
+    match account.get_extension::<TransferFeeAmount>() {
+        Ok(fee_amount) => Ok(u64::from(fee_amount.withheld_amount)),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Harvest withheld transfer fees to the mint so the account can be closed
+pub fn harvest_withheld_fees<'info>(
+    account: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    let withheld = {
+        let account_info = account.to_account_info();
+        let data = account_info.try_borrow_data()?;
+        withheld_fees_from_account_data(&data)?
+    };
+    if withheld == 0 {
+        return Ok(());
+    }
+
+    let ix = harvest_withheld_tokens_to_mint(&token_program.key(), &mint.key(), &[&account.key()])?;
+    invoke(
+        &ix,
+        &[
+            mint.to_account_info(),
+            account.to_account_info(),
+            token_program.to_account_info(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Check if a mint is the native SOL (wrapped SOL) mint
+pub fn is_native_mint(mint: &Pubkey) -> bool {
+    *mint == spl_token::native_mint::ID || *mint == spl_token_2022::native_mint::ID
+}
+
+/// Non-fungible mints have no decimals and a supply of exactly one
+pub fn is_nft(decimals: u8, supply: u64) -> bool {
+    decimals == 0 && supply == 1
+}
+
+/// Transfer lamports from a system account
+pub fn transfer_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let cpi_accounts = system_program::Transfer {
+        from: from.to_account_info(),
+        to: to.to_account_info(),
+    };
+
+    system_program::transfer(
+        CpiContext::new(system_program.to_account_info(), cpi_accounts),
+        amount,
+    )
+}
+
+/// Wrap native SOL into a wSOL token account
+pub fn wrap_sol<'info>(
+    from: &AccountInfo<'info>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    system_program: &Program<'info, System>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    transfer_lamports(from, &to.to_account_info(), system_program, amount)?;
+
+    let cpi_accounts = SyncNative {
+        account: to.to_account_info(),
+    };
+
+    sync_native(CpiContext::new(
+        token_program.to_account_info(),
+        cpi_accounts,
+    ))
+}
+
 /// Validate token account matches expected mint and authority
 pub fn validate_token_account(
     account: &InterfaceAccount<TokenAccount>,
@@ -85,3 +284,155 @@ pub fn check_sufficient_balance(
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_native_mint() {
+        assert!(is_native_mint(&spl_token::native_mint::ID));
+        assert!(is_native_mint(&spl_token_2022::native_mint::ID));
+        assert!(!is_native_mint(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_is_nft() {
+        assert!(is_nft(0, 1));
+        assert!(!is_nft(0, 2));
+        assert!(!is_nft(6, 1));
+        assert!(!is_nft(0, 0));
+    }
+
+    fn mint_with_transfer_fee(fee_basis_points: u16, maximum_fee: u64) -> Vec<u8> {
+        use spl_token_2022::extension::{
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        };
+
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::TransferFeeConfig,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+                .unwrap();
+        let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.newer_transfer_fee.transfer_fee_basis_points = fee_basis_points.into();
+        config.newer_transfer_fee.maximum_fee = maximum_fee.into();
+        config.older_transfer_fee = config.newer_transfer_fee;
+        state.base = spl_token_2022::state::Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn test_mint_features_from_data() {
+        use anchor_lang::solana_program::program_option::COption;
+        use spl_token_2022::extension::{
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        };
+
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::PermanentDelegate,
+            ExtensionType::NonTransferable,
+            ExtensionType::DefaultAccountState,
+            ExtensionType::MintCloseAuthority,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+                .unwrap();
+        state
+            .init_extension::<PermanentDelegate>(true)
+            .unwrap()
+            .delegate = Some(Pubkey::new_unique()).try_into().unwrap();
+        state.init_extension::<NonTransferable>(true).unwrap();
+        state
+            .init_extension::<DefaultAccountState>(true)
+            .unwrap()
+            .state = AccountState::Frozen as u8;
+        state
+            .init_extension::<MintCloseAuthority>(true)
+            .unwrap()
+            .close_authority = Some(Pubkey::new_unique()).try_into().unwrap();
+        state.base = spl_token_2022::state::Mint {
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::Some(Pubkey::new_unique()),
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+
+        assert_eq!(mint_features_from_data(&data).unwrap(), MINT_FEATURE_ALL);
+
+        // A plain mint without a freeze authority has no risky features
+        let mut plain = vec![0u8; 82];
+        plain[45] = 1;
+        assert_eq!(mint_features_from_data(&plain).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_transfer_hook_program_from_mint_data() {
+        use spl_token_2022::extension::{
+            transfer_hook::TransferHook, BaseStateWithExtensionsMut, ExtensionType,
+            StateWithExtensionsMut,
+        };
+
+        let hook_program = Pubkey::new_unique();
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::TransferHook,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+                .unwrap();
+        let hook = state.init_extension::<TransferHook>(true).unwrap();
+        hook.program_id = Some(hook_program).try_into().unwrap();
+        state.base = spl_token_2022::state::Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+
+        assert_eq!(
+            transfer_hook_program_from_mint_data(&data).unwrap(),
+            Some(hook_program)
+        );
+
+        // Mints without the extension have no hook
+        let mut plain = vec![0u8; 82];
+        plain[45] = 1;
+        assert_eq!(transfer_hook_program_from_mint_data(&plain).unwrap(), None);
+    }
+
+    #[test]
+    fn test_transfer_fee_from_mint_data() {
+        let data = mint_with_transfer_fee(100, 5_000);
+        assert_eq!(
+            transfer_fee_from_mint_data(&data, 0, 100_000).unwrap(),
+            1_000
+        );
+        // Fee is capped at the maximum
+        assert_eq!(
+            transfer_fee_from_mint_data(&data, 0, 10_000_000).unwrap(),
+            5_000
+        );
+        assert_eq!(transfer_fee_from_mint_data(&data, 0, 0).unwrap(), 0);
+
+        // Mints without the extension charge nothing
+        let mut plain = vec![0u8; 82];
+        plain[45] = 1;
+        assert_eq!(transfer_fee_from_mint_data(&plain, 0, 100_000).unwrap(), 0);
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{constants::*, errors::SelixError, state::Platform};
+use crate::{
+    constants::*,
+    errors::SelixError,
+    state::{Platform, TokenWhitelist},
+};
 use anchor_lang::prelude::*;
 
 /// Validate amount is above minimum
@@ -48,6 +52,83 @@ pub fn validate_min_fill_amount(min_fill: u64, total_amount: u64) -> Result<()>
     Ok(())
 }
 
+/// Validate the taker set of a private listing
+pub fn validate_allowed_takers(allowed_takers: &[Pubkey], maker: &Pubkey) -> Result<()> {
+    require!(
+        allowed_takers.len() <= MAX_ALLOWED_TAKERS,
+        SelixError::TooManyAllowedTakers
+    );
+    require!(
+        !allowed_takers.contains(maker),
+        SelixError::CannotSwapOwnListing
+    );
+    Ok(())
+}
+
+/// Validate the visible clip size of an iceberg listing
+/// An all-or-none listing is filled whole, so it cannot be shown in clips
+pub fn validate_display_amount(
+    display_amount: u64,
+    total_amount: u64,
+    min_fill: u64,
+    all_or_none: bool,
+) -> Result<()> {
+    require!(!all_or_none, SelixError::InvalidDisplayAmount);
+    require!(display_amount > 0, SelixError::InvalidDisplayAmount);
+    require!(
+        display_amount < total_amount,
+        SelixError::InvalidDisplayAmount
+    );
+    require!(display_amount >= min_fill, SelixError::InvalidDisplayAmount);
+    Ok(())
+}
+
+/// Validate a release schedule fits within the listing duration
+pub fn validate_release_schedule(
+    cliff_seconds: i64,
+    release_seconds: i64,
+    listing_duration: i64,
+) -> Result<()> {
+    require!(release_seconds > 0, SelixError::InvalidReleaseSchedule);
+    require!(
+        cliff_seconds >= 0 && cliff_seconds <= release_seconds,
+        SelixError::InvalidReleaseSchedule
+    );
+    require!(
+        release_seconds <= listing_duration,
+        SelixError::InvalidReleaseSchedule
+    );
+    Ok(())
+}
+
+/// Validate Dutch auction curve parameters
+pub fn validate_dutch_auction(
+    start_amount: u64,
+    floor_amount: u64,
+    step_seconds: i64,
+    duration: i64,
+) -> Result<()> {
+    require!(floor_amount > 0, SelixError::InvalidAuctionParameters);
+    require!(
+        floor_amount < start_amount,
+        SelixError::InvalidAuctionParameters
+    );
+    require!(
+        step_seconds >= 0 && step_seconds < duration,
+        SelixError::InvalidAuctionParameters
+    );
+    Ok(())
+}
+
+/// Validate an oracle premium or discount
+pub fn validate_oracle_premium(premium_bps: i16) -> Result<()> {
+    require!(
+        (-MAX_ORACLE_PREMIUM_BPS..=MAX_ORACLE_PREMIUM_BPS).contains(&premium_bps),
+        SelixError::InvalidOraclePremium
+    );
+    Ok(())
+}
+
 /// Validate platform is not paused
 pub fn validate_not_paused(platform: &Platform) -> Result<()> {
     require!(!platform.is_paused, SelixError::PlatformPaused);
@@ -66,6 +147,16 @@ pub fn validate_future_timestamp(timestamp: i64, current_time: i64) -> Result<()
     Ok(())
 }
 
+/// Validate a scheduled start time is in the future and not too far out
+pub fn validate_start_time(starts_at: i64, current_time: i64, platform: &Platform) -> Result<()> {
+    require!(starts_at > current_time, SelixError::InvalidStartTime);
+    require!(
+        starts_at - current_time <= platform.max_listing_duration,
+        SelixError::InvalidStartTime
+    );
+    Ok(())
+}
+
 /// Validate duration bounds configuration
 pub fn validate_duration_bounds(min_duration: i64, max_duration: i64) -> Result<()> {
     require!(
@@ -75,6 +166,46 @@ pub fn validate_duration_bounds(min_duration: i64, max_duration: i64) -> Result<
     Ok(())
 }
 
+/// Validate a listed amount: NFTs trade exactly one token and skip the
+/// dust check, fungible tokens must meet the minimum trade amount
+pub fn validate_trade_amount(amount: u64, is_nft: bool, min_amount: u64) -> Result<()> {
+    if is_nft {
+        require!(amount == 1, SelixError::InvalidNftAmount);
+        Ok(())
+    } else {
+        validate_amount(amount, min_amount)
+    }
+}
+
+/// Validate a mint feature bitmask only uses known MINT_FEATURE_* bits
+pub fn validate_mint_feature_mask(mask: u8) -> Result<()> {
+    require!(
+        mask & !MINT_FEATURE_ALL == 0,
+        SelixError::InvalidMintFeaturePolicy
+    );
+    Ok(())
+}
+
+/// Validate a mint has none of the features rejected by the platform policy
+pub fn validate_mint_features(features: u8, rejected: u8) -> Result<()> {
+    require!(features & rejected == 0, SelixError::MintFeatureRejected);
+    Ok(())
+}
+
+/// Validate a token is whitelisted through its whitelist PDA
+pub fn validate_whitelisted(
+    whitelist: Option<&Account<TokenWhitelist>>,
+    mint: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let whitelist = whitelist.ok_or(SelixError::TokenNotWhitelisted)?;
+    let (expected_pda, _) =
+        Pubkey::find_program_address(&[WHITELIST_SEED, mint.as_ref()], program_id);
+    require_keys_eq!(whitelist.key(), expected_pda, SelixError::InvalidPDA);
+    require!(whitelist.is_whitelisted, SelixError::TokenNotWhitelisted);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,6 +217,15 @@ mod tests {
         assert!(validate_amount(50, 100).is_err());
     }
 
+    #[test]
+    fn test_validate_trade_amount() {
+        assert!(validate_trade_amount(1, true, 1000).is_ok());
+        assert!(validate_trade_amount(2, true, 1000).is_err());
+        assert!(validate_trade_amount(0, true, 1000).is_err());
+        assert!(validate_trade_amount(1000, false, 1000).is_ok());
+        assert!(validate_trade_amount(1, false, 1000).is_err());
+    }
+
     #[test]
     fn test_validate_different_mints() {
         let mint_a = Pubkey::new_unique();
@@ -107,4 +247,80 @@ mod tests {
         assert!(validate_min_fill_amount(1000, 1000).is_ok());
         assert!(validate_min_fill_amount(1001, 1000).is_err());
     }
+
+    #[test]
+    fn test_validate_display_amount() {
+        assert!(validate_display_amount(100, 1000, 50, false).is_ok());
+        assert!(validate_display_amount(100, 1000, 100, false).is_ok());
+        assert!(validate_display_amount(0, 1000, 0, false).is_err());
+        assert!(validate_display_amount(1000, 1000, 50, false).is_err());
+        assert!(validate_display_amount(100, 1000, 200, false).is_err());
+    }
+
+    #[test]
+    fn test_validate_display_amount_rejects_all_or_none() {
+        assert!(validate_display_amount(100, 1000, 0, true).is_err());
+        assert!(validate_display_amount(100, 1000, 50, true).is_err());
+    }
+
+    #[test]
+    fn test_validate_release_schedule() {
+        assert!(validate_release_schedule(0, 3600, 86400).is_ok());
+        assert!(validate_release_schedule(3600, 3600, 86400).is_ok());
+        assert!(validate_release_schedule(0, 0, 86400).is_err());
+        assert!(validate_release_schedule(-1, 3600, 86400).is_err());
+        assert!(validate_release_schedule(7200, 3600, 86400).is_err());
+        assert!(validate_release_schedule(0, 86401, 86400).is_err());
+    }
+
+    #[test]
+    fn test_validate_dutch_auction() {
+        assert!(validate_dutch_auction(2000, 1000, 0, 3600).is_ok());
+        assert!(validate_dutch_auction(2000, 1000, 600, 3600).is_ok());
+        assert!(validate_dutch_auction(2000, 0, 0, 3600).is_err());
+        assert!(validate_dutch_auction(2000, 2000, 0, 3600).is_err());
+        assert!(validate_dutch_auction(2000, 1000, 3600, 3600).is_err());
+        assert!(validate_dutch_auction(2000, 1000, -1, 3600).is_err());
+    }
+
+    #[test]
+    fn test_validate_oracle_premium() {
+        assert!(validate_oracle_premium(0).is_ok());
+        assert!(validate_oracle_premium(MAX_ORACLE_PREMIUM_BPS).is_ok());
+        assert!(validate_oracle_premium(-MAX_ORACLE_PREMIUM_BPS).is_ok());
+        assert!(validate_oracle_premium(MAX_ORACLE_PREMIUM_BPS + 1).is_err());
+        assert!(validate_oracle_premium(-MAX_ORACLE_PREMIUM_BPS - 1).is_err());
+    }
+
+    #[test]
+    fn test_validate_mint_features() {
+        assert!(validate_mint_feature_mask(MINT_FEATURE_ALL).is_ok());
+        assert!(validate_mint_feature_mask(MINT_FEATURE_ALL + 1).is_err());
+
+        assert!(validate_mint_features(0, DEFAULT_REJECTED_MINT_FEATURES).is_ok());
+        assert!(validate_mint_features(
+            MINT_FEATURE_FREEZE_AUTHORITY,
+            DEFAULT_REJECTED_MINT_FEATURES
+        )
+        .is_ok());
+        assert!(validate_mint_features(
+            MINT_FEATURE_PERMANENT_DELEGATE,
+            DEFAULT_REJECTED_MINT_FEATURES
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_allowed_takers() {
+        let maker = Pubkey::new_unique();
+        let taker = Pubkey::new_unique();
+        assert!(validate_allowed_takers(&[], &maker).is_ok());
+        assert!(validate_allowed_takers(&[taker], &maker).is_ok());
+        assert!(validate_allowed_takers(&[taker, maker], &maker).is_err());
+
+        let too_many: Vec<Pubkey> = (0..=MAX_ALLOWED_TAKERS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        assert!(validate_allowed_takers(&too_many, &maker).is_err());
+    }
 }
@@ -1,16 +1,2274 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
+import { AnchorError, BN, Program } from "@coral-xyz/anchor";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  ExtensionType,
+  NATIVE_MINT,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  approve,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  createSyncNativeInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import {
+  AccountMeta,
+  AddressLookupTableProgram,
+  ComputeBudgetProgram,
+  Ed25519Program,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+  TransactionMessage,
+  VersionedTransaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import { expect } from "chai";
 import { Selix } from "../target/types/selix";
 
 describe("selix", () => {
   // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
 
   const program = anchor.workspace.selix as Program<Selix>;
+  const connection = provider.connection;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const feeCollector = Keypair.generate();
+  const eventParser = new anchor.EventParser(program.programId, program.coder);
 
-  it("Is initialized!", async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+  const FEE_BPS = 25;
+  const LISTING_DURATION = new BN(3_600);
+
+  const [platform] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform"), payer.publicKey.toBuffer()],
+    program.programId
+  );
+
+  const profilePda = (user: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("user_profile"), user.toBuffer()],
+      program.programId
+    )[0];
+
+  const listingPda = (maker: PublicKey, id: number) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("listing"),
+        maker.toBuffer(),
+        new BN(id).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    )[0];
+
+  // Token program of every mint created by the tests (SPL Token otherwise)
+  const mintPrograms = new Map<string, PublicKey>();
+  const programOf = (mint: PublicKey) =>
+    mintPrograms.get(mint.toBase58()) ?? TOKEN_PROGRAM_ID;
+
+  const ata = (mint: PublicKey, owner: PublicKey) =>
+    getAssociatedTokenAddressSync(mint, owner, true, programOf(mint));
+
+  const writable = (pubkey: PublicKey): AccountMeta => ({
+    pubkey,
+    isSigner: false,
+    isWritable: true,
+  });
+
+  const readonly = (pubkey: PublicKey): AccountMeta => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  });
+
+  const fee = (amount: number) => Math.floor((amount * FEE_BPS) / 10_000);
+
+  async function balance(mint: PublicKey, owner: PublicKey): Promise<number> {
+    const account = await getAccount(
+      connection,
+      ata(mint, owner),
+      undefined,
+      programOf(mint)
+    );
+    return Number(account.amount);
+  }
+
+  async function newMint(
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+  ): Promise<PublicKey> {
+    const mint = await createMint(
+      connection,
+      payer,
+      payer.publicKey,
+      null,
+      6,
+      undefined,
+      undefined,
+      tokenProgram
+    );
+    mintPrograms.set(mint.toBase58(), tokenProgram);
+    return mint;
+  }
+
+  // Token-2022 mint withholding `feeBps` of every transfer
+  async function newTransferFeeMint(feeBps: number): Promise<PublicKey> {
+    const mint = Keypair.generate();
+    const space = getMintLen([ExtensionType.TransferFeeConfig]);
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: mint.publicKey,
+        space,
+        lamports: await connection.getMinimumBalanceForRentExemption(space),
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      createInitializeTransferFeeConfigInstruction(
+        mint.publicKey,
+        payer.publicKey,
+        payer.publicKey,
+        feeBps,
+        BigInt(1_000_000_000),
+        TOKEN_2022_PROGRAM_ID
+      ),
+      createInitializeMintInstruction(
+        mint.publicKey,
+        6,
+        payer.publicKey,
+        null,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    await sendAndConfirmTransaction(connection, tx, [payer, mint]);
+    mintPrograms.set(mint.publicKey.toBase58(), TOKEN_2022_PROGRAM_ID);
+    return mint.publicKey;
+  }
+
+  // Create the owner's token account and mint `amount` into it
+  async function fund(
+    mint: PublicKey,
+    owner: PublicKey,
+    amount: number
+  ): Promise<PublicKey> {
+    const account = await getOrCreateAssociatedTokenAccount(
+      connection,
+      payer,
+      mint,
+      owner,
+      true,
+      undefined,
+      undefined,
+      programOf(mint)
+    );
+    if (amount > 0) {
+      await mintTo(
+        connection,
+        payer,
+        mint,
+        account.address,
+        payer,
+        amount,
+        [],
+        undefined,
+        programOf(mint)
+      );
+    }
+    return account.address;
+  }
+
+  // Airdropped wallet with an initialized user profile
+  async function newUser(): Promise<Keypair> {
+    const user = Keypair.generate();
+    const signature = await connection.requestAirdrop(
+      user.publicKey,
+      10 * LAMPORTS_PER_SOL
+    );
+    const latest = await connection.getLatestBlockhash();
+    await connection.confirmTransaction({ signature, ...latest }, "confirmed");
+
+    await program.methods
+      .initializeUser({
+        referrer: null,
+        defaultListingDuration: new BN(86_400),
+        defaultSlippageBps: 100,
+      })
+      .accountsPartial({
+        user: user.publicKey,
+        userProfile: profilePda(user.publicKey),
+        referrer: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    return user;
+  }
+
+  const listingParams = (
+    id: number,
+    amountSource: number,
+    amountDestination: number,
+    overrides: object = {}
+  ) => ({
+    id: new BN(id),
+    side: { ask: {} },
+    amountSource: new BN(amountSource),
+    amountDestination: new BN(amountDestination),
+    minFillAmount: new BN(0),
+    maxSlippageBps: 100,
+    durationSeconds: LISTING_DURATION,
+    goodTillCancelled: false,
+    allOrNone: false,
+    displayAmount: null,
+    allowedTakers: [],
+    dutchAuction: null,
+    oraclePremiumBps: null,
+    startsAt: null,
+    releaseSchedule: null,
+    trigger: null,
+    destinationCollection: false,
+    ...overrides,
+  });
+
+  let nextListingId = 1;
+
+  async function createListing(
+    maker: Keypair,
+    mintSource: PublicKey,
+    mintDestination: PublicKey,
+    amountSource: number,
+    amountDestination: number,
+    overrides: object = {}
+  ): Promise<PublicKey> {
+    const id = nextListingId++;
+    const listing = listingPda(maker.publicKey, id);
+
+    await program.methods
+      .createListing(
+        listingParams(id, amountSource, amountDestination, overrides)
+      )
+      .accountsPartial({
+        maker: maker.publicKey,
+        makerProfile: profilePda(maker.publicKey),
+        platform,
+        listing,
+        vault: ata(mintSource, listing),
+        makerTokenAccountSource: mintSource.equals(NATIVE_MINT)
+          ? null
+          : ata(mintSource, maker.publicKey),
+        tokenMintSource: mintSource,
+        tokenMintDestination: mintDestination,
+        sourceWhitelist: null,
+        destWhitelist: null,
+        priceFeed: null,
+        tokenProgramSource: programOf(mintSource),
+        tokenProgramDestination: programOf(mintDestination),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([maker])
+      .rpc();
+
+    return listing;
+  }
+
+  // Fill `amountSource` of a listing. A native SOL destination is paid from
+  // the taker's wallet, without destination token accounts.
+  async function swap(
+    taker: Keypair,
+    maker: Keypair,
+    listing: PublicKey,
+    amountSource: number,
+    maxAmountDestination: number,
+    makerProfile: PublicKey | null = profilePda(maker.publicKey)
+  ): Promise<string> {
+    const state = await program.account.listing.fetch(listing);
+    const mintSource = state.tokenMintSource;
+    const mintDestination = state.tokenMintDestination;
+    const destinationAccount = (owner: PublicKey) =>
+      mintDestination.equals(NATIVE_MINT) ? null : ata(mintDestination, owner);
+
+    return program.methods
+      .executeSwap({
+        amountSource: new BN(amountSource),
+        maxAmountDestination: new BN(maxAmountDestination),
+      })
+      .accountsPartial({
+        taker: taker.publicKey,
+        takerProfile: profilePda(taker.publicKey),
+        maker: maker.publicKey,
+        makerProfile,
+        platform,
+        feeCollector: feeCollector.publicKey,
+        listing,
+        vault: ata(mintSource, listing),
+        takerTokenAccountSource: ata(mintSource, taker.publicKey),
+        takerTokenAccountDestination: destinationAccount(taker.publicKey),
+        makerTokenAccountDestination: destinationAccount(maker.publicKey),
+        feeCollectorTokenAccount: destinationAccount(feeCollector.publicKey),
+        tokenMintSource: mintSource,
+        tokenMintDestination: mintDestination,
+        destinationMetadata: null,
+        priceFeed: null,
+        tokenProgramSource: programOf(mintSource),
+        tokenProgramDestination: programOf(mintDestination),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([taker])
+      .rpc();
+  }
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+    } catch (err) {
+      if (err instanceof AnchorError) {
+        expect(err.error.errorCode.code).to.equal(code);
+      } else {
+        expect(String(err)).to.include(code);
+      }
+      return;
+    }
+    expect.fail(`expected ${code}`);
+  }
+
+  async function eventsOf(signature: string, name: string) {
+    const tx = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    return [...eventParser.parseLogs(tx.meta.logMessages)]
+      .filter((event) => event.name.toLowerCase() === name.toLowerCase())
+      .map((event) => event.data as any);
+  }
+
+  before(async () => {
+    await program.methods
+      .initializePlatform({
+        feeBasisPoints: FEE_BPS,
+        minListingDuration: new BN(300),
+        maxListingDuration: new BN(2_592_000),
+        minTradeAmount: new BN(1_000),
+        maxListingsPerUser: 100,
+      })
+      .accountsPartial({
+        authority: payer.publicKey,
+        platform,
+        feeCollector: feeCollector.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Native SOL fees are paid to the collector wallet, which must be rent exempt
+    const signature = await connection.requestAirdrop(
+      feeCollector.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    const latest = await connection.getLatestBlockhash();
+    await connection.confirmTransaction({ signature, ...latest }, "confirmed");
+  });
+
+  it("initializes the platform", async () => {
+    const state = await program.account.platform.fetch(platform);
+    expect(state.feeCollector.toBase58()).to.equal(
+      feeCollector.publicKey.toBase58()
+    );
+    expect(state.feeBasisPoints).to.equal(FEE_BPS);
+    expect(state.isPaused).to.equal(false);
+  });
+
+  describe("match_listings", () => {
+    let mintA: PublicKey;
+    let mintB: PublicKey;
+    let cranker: Keypair;
+
+    before(async () => {
+      mintA = await newMint();
+      mintB = await newMint();
+      cranker = await newUser();
+    });
+
+    const matchListings = (
+      makerA: Keypair,
+      makerB: Keypair,
+      listingA: PublicKey,
+      listingB: PublicKey
+    ) =>
+      program.methods
+        .matchListings()
+        .accountsPartial({
+          cranker: cranker.publicKey,
+          platform,
+          feeCollector: feeCollector.publicKey,
+          makerA: makerA.publicKey,
+          makerB: makerB.publicKey,
+          makerAProfile: profilePda(makerA.publicKey),
+          makerBProfile: profilePda(makerB.publicKey),
+          listingA,
+          listingB,
+          vaultA: ata(mintA, listingA),
+          vaultB: ata(mintB, listingB),
+          makerATokenAccount: ata(mintB, makerA.publicKey),
+          makerBTokenAccount: ata(mintA, makerB.publicKey),
+          feeCollectorTokenAccountA: ata(mintA, feeCollector.publicKey),
+          feeCollectorTokenAccountB: ata(mintB, feeCollector.publicKey),
+          tokenMintA: mintA,
+          tokenMintB: mintB,
+          tokenProgramA: TOKEN_PROGRAM_ID,
+          tokenProgramB: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([cranker])
+        .rpc();
+
+    it("settles two crossing listings as one swap", async () => {
+      const makerA = await newUser();
+      const makerB = await newUser();
+      await fund(mintA, makerA.publicKey, 1_000_000);
+      await fund(mintB, makerB.publicKey, 2_000_000);
+
+      const listingA = await createListing(
+        makerA,
+        mintA,
+        mintB,
+        1_000_000,
+        2_000_000
+      );
+      const listingB = await createListing(
+        makerB,
+        mintB,
+        mintA,
+        2_000_000,
+        1_000_000
+      );
+      const platformBefore = await program.account.platform.fetch(platform);
+
+      await matchListings(makerA, makerB, listingA, listingB);
+
+      // Each maker receives their price minus the platform fee
+      expect(await balance(mintB, makerA.publicKey)).to.equal(
+        2_000_000 - fee(2_000_000)
+      );
+      expect(await balance(mintA, makerB.publicKey)).to.equal(
+        1_000_000 - fee(1_000_000)
+      );
+
+      const stateA = await program.account.listing.fetch(listingA);
+      const stateB = await program.account.listing.fetch(listingB);
+      expect(stateA.status).to.deep.equal({ completed: {} });
+      expect(stateB.status).to.deep.equal({ completed: {} });
+
+      // One match is one swap, recorded in listing A's quote token (B);
+      // makers are credited their gross quote volume
+      const platformState = await program.account.platform.fetch(platform);
+      expect(platformState.totalSwapsExecuted.toNumber()).to.equal(
+        platformBefore.totalSwapsExecuted.toNumber() + 1
+      );
+      expect(
+        platformState.totalVolumeTraded
+          .sub(platformBefore.totalVolumeTraded)
+          .toNumber()
+      ).to.equal(2_000_000);
+      expect(
+        platformState.totalFeesCollected
+          .sub(platformBefore.totalFeesCollected)
+          .toNumber()
+      ).to.equal(fee(2_000_000));
+      const profileA = await program.account.userProfile.fetch(
+        profilePda(makerA.publicKey)
+      );
+      expect(profileA.volumeAsMaker.toNumber()).to.equal(2_000_000);
+      expect(profileA.activeListings).to.equal(0);
+    });
+
+    it("rejects listings whose prices do not cross", async () => {
+      const makerA = await newUser();
+      const makerB = await newUser();
+      await fund(mintA, makerA.publicKey, 1_000_000);
+      await fund(mintB, makerB.publicKey, 2_000_000);
+
+      // A asks 3 B per A, B only offers 2 B per A
+      const listingA = await createListing(
+        makerA,
+        mintA,
+        mintB,
+        1_000_000,
+        3_000_000
+      );
+      const listingB = await createListing(
+        makerB,
+        mintB,
+        mintA,
+        2_000_000,
+        1_000_000
+      );
+
+      await expectError(
+        matchListings(makerA, makerB, listingA, listingB),
+        "ListingsDoNotCross"
+      );
+    });
+
+    it("rejects matching a maker against themselves", async () => {
+      const maker = await newUser();
+      await fund(mintA, maker.publicKey, 1_000_000);
+      await fund(mintB, maker.publicKey, 2_000_000);
+
+      const listingA = await createListing(
+        maker,
+        mintA,
+        mintB,
+        1_000_000,
+        2_000_000
+      );
+      const listingB = await createListing(
+        maker,
+        mintB,
+        mintA,
+        2_000_000,
+        1_000_000
+      );
+
+      await expectError(
+        matchListings(maker, maker, listingA, listingB),
+        "CannotSwapOwnListing"
+      );
+    });
+  });
+
+  // Remaining accounts of one route hop, paying `mintIn` for `mintOut`
+  const routeHop = (
+    taker: Keypair,
+    maker: Keypair,
+    listing: PublicKey,
+    mintIn: PublicKey,
+    mintOut: PublicKey
+  ): AccountMeta[] => [
+    writable(listing),
+    writable(ata(mintOut, listing)),
+    writable(maker.publicKey),
+    writable(profilePda(maker.publicKey)),
+    writable(ata(mintIn, maker.publicKey)),
+    writable(ata(mintIn, feeCollector.publicKey)),
+    writable(ata(mintOut, taker.publicKey)),
+    writable(mintOut),
+    readonly(TOKEN_PROGRAM_ID),
+  ];
+
+  const executeRoute = (
+    taker: Keypair,
+    mintIn: PublicKey,
+    amountIn: number,
+    minAmountOut: number,
+    hops: AccountMeta[][]
+  ) =>
+    program.methods
+      .executeRoute({
+        amountIn: new BN(amountIn),
+        minAmountOut: new BN(minAmountOut),
+        hops: hops.length,
+      })
+      .accountsPartial({
+        taker: taker.publicKey,
+        takerProfile: null,
+        platform,
+        takerTokenAccountIn: ata(mintIn, taker.publicKey),
+        tokenMintIn: mintIn,
+        tokenProgramIn: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(hops.flat())
+      .signers([taker])
+      .rpc({ commitment: "confirmed" });
+
+  describe("execute_route", () => {
+    let mintA: PublicKey;
+    let mintB: PublicKey;
+    let mintC: PublicKey;
+    let taker: Keypair;
+    let makerB: Keypair;
+    let makerC: Keypair;
+
+    before(async () => {
+      mintA = await newMint();
+      mintB = await newMint();
+      mintC = await newMint();
+
+      // makerB sells B for A, makerC sells C for B
+      taker = await newUser();
+      makerB = await newUser();
+      makerC = await newUser();
+      await fund(mintA, taker.publicKey, 10_000_000);
+      await fund(mintB, taker.publicKey, 0);
+      await fund(mintC, taker.publicKey, 0);
+      await fund(mintB, makerB.publicKey, 10_000_000);
+      await fund(mintA, makerB.publicKey, 0);
+      await fund(mintC, makerC.publicKey, 10_000_000);
+      await fund(mintB, makerC.publicKey, 0);
+      await fund(mintA, feeCollector.publicKey, 0);
+      await fund(mintB, feeCollector.publicKey, 0);
+    });
+
+    it("fills A to B to C across two listings", async () => {
+      const listingB = await createListing(
+        makerB,
+        mintB,
+        mintA,
+        1_000_000,
+        1_000_000
+      );
+      const listingC = await createListing(
+        makerC,
+        mintC,
+        mintB,
+        500_000,
+        1_000_000
+      );
+      const takerA = await balance(mintA, taker.publicKey);
+      const takerB = await balance(mintB, taker.publicKey);
+      const takerC = await balance(mintC, taker.publicKey);
+
+      const signature = await executeRoute(taker, mintA, 400_000, 200_000, [
+        routeHop(taker, makerB, listingB, mintA, mintB),
+        routeHop(taker, makerC, listingC, mintB, mintC),
+      ]);
+
+      // Every intermediate token is spent by the next hop
+      expect(await balance(mintA, taker.publicKey)).to.equal(takerA - 400_000);
+      expect(await balance(mintB, taker.publicKey)).to.equal(takerB);
+      expect(await balance(mintC, taker.publicKey)).to.equal(takerC + 200_000);
+
+      const [route] = await eventsOf(signature, "RouteExecuted");
+      expect(route.amountIn.toNumber()).to.equal(400_000);
+      expect(route.amountOut.toNumber()).to.equal(200_000);
+      expect(
+        route.amountsUnspent.map((amount: BN) => amount.toNumber())
+      ).to.deep.equal([0, 0]);
+    });
+
+    it("reports intermediate tokens a capped hop leaves unspent", async () => {
+      const listingB = await createListing(
+        makerB,
+        mintB,
+        mintA,
+        1_000_000,
+        1_000_000
+      );
+      // Only 100_000 C for 200_000 B: the second hop cannot spend 400_000 B
+      const listingC = await createListing(
+        makerC,
+        mintC,
+        mintB,
+        100_000,
+        200_000
+      );
+      const takerB = await balance(mintB, taker.publicKey);
+      const takerC = await balance(mintC, taker.publicKey);
+
+      const signature = await executeRoute(taker, mintA, 400_000, 100_000, [
+        routeHop(taker, makerB, listingB, mintA, mintB),
+        routeHop(taker, makerC, listingC, mintB, mintC),
+      ]);
+
+      // The residual stays with the taker and is reported per hop
+      expect(await balance(mintB, taker.publicKey)).to.equal(takerB + 200_000);
+      expect(await balance(mintC, taker.publicKey)).to.equal(takerC + 100_000);
+
+      const [route] = await eventsOf(signature, "RouteExecuted");
+      expect(route.amountIn.toNumber()).to.equal(400_000);
+      expect(route.amountOut.toNumber()).to.equal(100_000);
+      expect(
+        route.amountsUnspent.map((amount: BN) => amount.toNumber())
+      ).to.deep.equal([0, 200_000]);
+    });
+
+    it("rejects a route below the minimum output", async () => {
+      const listingB = await createListing(
+        makerB,
+        mintB,
+        mintA,
+        1_000_000,
+        1_000_000
+      );
+      const listingC = await createListing(
+        makerC,
+        mintC,
+        mintB,
+        500_000,
+        1_000_000
+      );
+
+      await expectError(
+        executeRoute(taker, mintA, 100_000, 100_000, [
+          routeHop(taker, makerB, listingB, mintA, mintB),
+          routeHop(taker, makerC, listingC, mintB, mintC),
+        ]),
+        "SlippageExceeded"
+      );
+    });
+
+    it("rejects hops that do not chain", async () => {
+      const listingB = await createListing(
+        makerB,
+        mintB,
+        mintA,
+        1_000_000,
+        1_000_000
+      );
+      const listingC = await createListing(
+        makerC,
+        mintC,
+        mintB,
+        500_000,
+        1_000_000
+      );
+
+      // The first hop must be paid in A, but listingC asks for B
+      await fund(mintA, makerC.publicKey, 0);
+      await expectError(
+        executeRoute(taker, mintA, 100_000, 0, [
+          routeHop(taker, makerC, listingC, mintA, mintC),
+          routeHop(taker, makerB, listingB, mintC, mintB),
+        ]),
+        "InvalidRoute"
+      );
+    });
+  });
+
+  // Remaining accounts of one swept listing, paid in `mintDestination`
+  const sweepListing = (
+    maker: Keypair,
+    listing: PublicKey,
+    mintSource: PublicKey,
+    mintDestination: PublicKey
+  ): AccountMeta[] => [
+    writable(listing),
+    writable(ata(mintSource, listing)),
+    writable(maker.publicKey),
+    writable(profilePda(maker.publicKey)),
+    writable(ata(mintDestination, maker.publicKey)),
+  ];
+
+  const sweepListings = (
+    taker: Keypair,
+    mintSource: PublicKey,
+    mintDestination: PublicKey,
+    params: {
+      maxAmountIn: number;
+      limitAmountSource: number;
+      limitAmountDestination: number;
+      minAmountOut: number;
+    },
+    listings: AccountMeta[][]
+  ) =>
+    program.methods
+      .sweepListings({
+        maxAmountIn: new BN(params.maxAmountIn),
+        limitAmountSource: new BN(params.limitAmountSource),
+        limitAmountDestination: new BN(params.limitAmountDestination),
+        minAmountOut: new BN(params.minAmountOut),
+        listings: listings.length,
+      })
+      .accountsPartial({
+        taker: taker.publicKey,
+        takerProfile: null,
+        platform,
+        feeCollector: feeCollector.publicKey,
+        takerTokenAccountSource: ata(mintSource, taker.publicKey),
+        takerTokenAccountDestination: ata(mintDestination, taker.publicKey),
+        feeCollectorTokenAccount: ata(mintDestination, feeCollector.publicKey),
+        tokenMintSource: mintSource,
+        tokenMintDestination: mintDestination,
+        tokenProgramSource: TOKEN_PROGRAM_ID,
+        tokenProgramDestination: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(listings.flat())
+      .signers([taker])
+      .rpc({ commitment: "confirmed" });
+
+  describe("sweep_listings", () => {
+    let mintS: PublicKey;
+    let mintD: PublicKey;
+    let taker: Keypair;
+    let makers: Keypair[];
+
+    before(async () => {
+      mintS = await newMint();
+      mintD = await newMint();
+
+      // Makers sell S for D, the taker pays D
+      taker = await newUser();
+      await fund(mintD, taker.publicKey, 10_000_000);
+      makers = [];
+      for (let i = 0; i < 3; i++) {
+        const maker = await newUser();
+        await fund(mintS, maker.publicKey, 10_000_000);
+        await fund(mintD, maker.publicKey, 0);
+        makers.push(maker);
+      }
+    });
+
+    it("fills the best prices first and reports skipped listings", async () => {
+      // 2, 3 and 5 D per S, passed out of price order
+      const price2 = await createListing(
+        makers[0],
+        mintS,
+        mintD,
+        100_000,
+        200_000
+      );
+      const price3 = await createListing(
+        makers[1],
+        mintS,
+        mintD,
+        100_000,
+        300_000
+      );
+      const price5 = await createListing(
+        makers[2],
+        mintS,
+        mintD,
+        100_000,
+        500_000
+      );
+      const takerD = await balance(mintD, taker.publicKey);
+
+      const signature = await sweepListings(
+        taker,
+        mintS,
+        mintD,
+        {
+          maxAmountIn: 1_000_000,
+          limitAmountSource: 1,
+          limitAmountDestination: 4,
+          minAmountOut: 200_000,
+        },
+        [
+          sweepListing(makers[2], price5, mintS, mintD),
+          sweepListing(makers[0], price2, mintS, mintD),
+          sweepListing(makers[1], price3, mintS, mintD),
+        ]
+      );
+
+      expect(await balance(mintS, taker.publicKey)).to.equal(200_000);
+      expect(await balance(mintD, taker.publicKey)).to.equal(takerD - 500_000);
+
+      const [sweep] = await eventsOf(signature, "SweepExecuted");
+      expect(sweep.amountIn.toNumber()).to.equal(500_000);
+      expect(sweep.amountOut.toNumber()).to.equal(200_000);
+      expect(
+        sweep.listings.map((key: PublicKey) => key.toBase58())
+      ).to.deep.equal([price2.toBase58(), price3.toBase58()]);
+
+      // The listing above the limit is reported, not silently passed over
+      const skipped = await eventsOf(signature, "SweepListingSkipped");
+      expect(skipped).to.have.length(1);
+      expect(skipped[0].listing.toBase58()).to.equal(price5.toBase58());
+      expect(skipped[0].reason).to.deep.equal({ priceAboveLimit: {} });
+    });
+
+    it("skips an all-or-none listing the budget cannot take whole", async () => {
+      const allOrNone = await createListing(
+        makers[0],
+        mintS,
+        mintD,
+        100_000,
+        100_000,
+        { allOrNone: true }
+      );
+      const price2 = await createListing(
+        makers[1],
+        mintS,
+        mintD,
+        100_000,
+        200_000
+      );
+      const takerS = await balance(mintS, taker.publicKey);
+
+      const signature = await sweepListings(
+        taker,
+        mintS,
+        mintD,
+        {
+          maxAmountIn: 50_000,
+          limitAmountSource: 1,
+          limitAmountDestination: 4,
+          minAmountOut: 0,
+        },
+        [
+          sweepListing(makers[0], allOrNone, mintS, mintD),
+          sweepListing(makers[1], price2, mintS, mintD),
+        ]
+      );
+
+      expect(await balance(mintS, taker.publicKey)).to.equal(takerS + 25_000);
+
+      const skipped = await eventsOf(signature, "SweepListingSkipped");
+      expect(skipped).to.have.length(1);
+      expect(skipped[0].listing.toBase58()).to.equal(allOrNone.toBase58());
+      expect(skipped[0].reason).to.deep.equal({ allOrNone: {} });
+    });
+
+    it("rejects a sweep below the minimum output", async () => {
+      const price2 = await createListing(
+        makers[0],
+        mintS,
+        mintD,
+        100_000,
+        200_000
+      );
+
+      await expectError(
+        sweepListings(
+          taker,
+          mintS,
+          mintD,
+          {
+            maxAmountIn: 200_000,
+            limitAmountSource: 1,
+            limitAmountDestination: 4,
+            minAmountOut: 200_000,
+          },
+          [sweepListing(makers[0], price2, mintS, mintD)]
+        ),
+        "SlippageExceeded"
+      );
+    });
+
+    it("rejects a sweep with no listing within the limit", async () => {
+      const price5 = await createListing(
+        makers[0],
+        mintS,
+        mintD,
+        100_000,
+        500_000
+      );
+
+      await expectError(
+        sweepListings(
+          taker,
+          mintS,
+          mintD,
+          {
+            maxAmountIn: 1_000_000,
+            limitAmountSource: 1,
+            limitAmountDestination: 4,
+            minAmountOut: 0,
+          },
+          [sweepListing(makers[0], price5, mintS, mintD)]
+        ),
+        "NoFillableListings"
+      );
+    });
+
+    it("rejects a listing of another pair", async () => {
+      // Sells D for S: the opposite side of the swept pair
+      await fund(mintD, makers[0].publicKey, 1_000_000);
+      const reversed = await createListing(
+        makers[0],
+        mintD,
+        mintS,
+        100_000,
+        100_000
+      );
+
+      await expectError(
+        sweepListings(
+          taker,
+          mintS,
+          mintD,
+          {
+            maxAmountIn: 1_000_000,
+            limitAmountSource: 1,
+            limitAmountDestination: 4,
+            minAmountOut: 0,
+          },
+          [sweepListing(makers[0], reversed, mintD, mintS)]
+        ),
+        "InvalidSweep"
+      );
+    });
+  });
+
+  describe("create_listings_batch / cancel_all_listings", () => {
+    let mintX: PublicKey;
+    let mintY: PublicKey;
+    let maker: Keypair;
+    let listings: PublicKey[];
+
+    before(async () => {
+      mintX = await newMint();
+      mintY = await newMint();
+      maker = await newUser();
+      await fund(mintX, maker.publicKey, 3_000_000);
+    });
+
+    const createListingsBatchBuilder = (
+      ids: number[],
+      listingAccounts: PublicKey[],
+      makerProfile = profilePda(maker.publicKey)
+    ) =>
+      program.methods
+        .createListingsBatch({
+          listings: ids.map((id) => listingParams(id, 1_000_000, 2_000_000)),
+        })
+        .accountsPartial({
+          maker: maker.publicKey,
+          makerProfile,
+          platform,
+          makerTokenAccountSource: ata(mintX, maker.publicKey),
+          tokenMintSource: mintX,
+          tokenMintDestination: mintY,
+          sourceWhitelist: null,
+          destWhitelist: null,
+          priceFeed: null,
+          tokenProgramSource: TOKEN_PROGRAM_ID,
+          tokenProgramDestination: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          listingAccounts.flatMap((listing) => [
+            writable(listing),
+            writable(ata(mintX, listing)),
+          ])
+        )
+        .signers([maker]);
+
+    const createListingsBatch = (
+      ids: number[],
+      listingAccounts: PublicKey[],
+      makerProfile = profilePda(maker.publicKey)
+    ) => createListingsBatchBuilder(ids, listingAccounts, makerProfile).rpc();
+
+    const cancelAllListingsBuilder = (
+      signer: Keypair,
+      listingAccounts: PublicKey[],
+      makerProfile = profilePda(signer.publicKey)
+    ) =>
+      program.methods
+        .cancelAllListings({ listings: listingAccounts.length })
+        .accountsPartial({ maker: signer.publicKey, makerProfile })
+        .remainingAccounts(
+          listingAccounts.flatMap((listing) => [
+            writable(listing),
+            writable(ata(mintX, listing)),
+            writable(ata(mintX, maker.publicKey)),
+            writable(mintX),
+            readonly(TOKEN_PROGRAM_ID),
+          ])
+        )
+        .signers([signer]);
+
+    const cancelAllListings = (
+      signer: Keypair,
+      listingAccounts: PublicKey[],
+      makerProfile = profilePda(signer.publicKey)
+    ) =>
+      cancelAllListingsBuilder(signer, listingAccounts, makerProfile).rpc();
+
+    // Send `instruction` as a v0 transaction whose accounts are all passed
+    // through a fresh address lookup table
+    async function sendWithLookupTable(
+      signer: Keypair,
+      instruction: TransactionInstruction
+    ) {
+      const slot = await connection.getSlot("finalized");
+      const [create, lookupTable] = AddressLookupTableProgram.createLookupTable(
+        {
+          authority: payer.publicKey,
+          payer: payer.publicKey,
+          recentSlot: slot,
+        }
+      );
+      const addresses = [
+        ...new Set(
+          [
+            instruction.programId,
+            ...instruction.keys.map((key) => key.pubkey),
+          ].map((key) => key.toBase58())
+        ),
+      ].map((key) => new PublicKey(key));
+      await sendAndConfirmTransaction(connection, new Transaction().add(create), [
+        payer,
+      ]);
+      for (let i = 0; i < addresses.length; i += 20) {
+        await sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(
+            AddressLookupTableProgram.extendLookupTable({
+              lookupTable,
+              authority: payer.publicKey,
+              payer: payer.publicKey,
+              addresses: addresses.slice(i, i + 20),
+            })
+          ),
+          [payer]
+        );
+      }
+      // Lookup table entries are usable from the slot after they are added
+      const extendedAt = await connection.getSlot();
+      while ((await connection.getSlot()) <= extendedAt) {
+        await new Promise((resolve) => setTimeout(resolve, 100));
+      }
+
+      const table = (await connection.getAddressLookupTable(lookupTable)).value!;
+      const latest = await connection.getLatestBlockhash();
+      const message = new TransactionMessage({
+        payerKey: signer.publicKey,
+        recentBlockhash: latest.blockhash,
+        instructions: [
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
+          instruction,
+        ],
+      }).compileToV0Message([table]);
+      const tx = new VersionedTransaction(message);
+      tx.sign([signer]);
+      const signature = await connection.sendTransaction(tx);
+      await connection.confirmTransaction(
+        { signature, ...latest },
+        "confirmed"
+      );
+    }
+
+    it("creates several listings in one instruction", async () => {
+      const ids = [nextListingId++, nextListingId++, nextListingId++];
+      listings = ids.map((id) => listingPda(maker.publicKey, id));
+
+      await createListingsBatch(ids, listings);
+
+      for (const listing of listings) {
+        expect(await balance(mintX, listing)).to.equal(1_000_000);
+        const state = await program.account.listing.fetch(listing);
+        expect(state.status).to.deep.equal({ active: {} });
+      }
+      expect(await balance(mintX, maker.publicKey)).to.equal(0);
+
+      const profile = await program.account.userProfile.fetch(
+        profilePda(maker.publicKey)
+      );
+      expect(profile.activeListings).to.equal(3);
+    });
+
+    it("rejects a listing account that does not match its id", async () => {
+      const id = nextListingId++;
+
+      await expectError(
+        createListingsBatch([id], [listingPda(maker.publicKey, id + 1)]),
+        "InvalidPDA"
+      );
+    });
+
+    it("rejects cancelling another maker's listings", async () => {
+      const other = await newUser();
+
+      await expectError(
+        cancelAllListings(other, [listings[0]]),
+        "UnauthorizedAuthority"
+      );
+    });
+
+    it("rejects another maker's profile", async () => {
+      const other = await newUser();
+
+      await expectError(
+        cancelAllListings(maker, listings, profilePda(other.publicKey)),
+        "ConstraintSeeds"
+      );
+    });
+
+    it("cancels all listings and returns their funds", async () => {
+      await cancelAllListings(maker, listings);
+
+      for (const listing of listings) {
+        expect(await program.account.listing.fetchNullable(listing)).to.equal(
+          null
+        );
+      }
+      expect(await balance(mintX, maker.publicKey)).to.equal(3_000_000);
+
+      const profile = await program.account.userProfile.fetch(
+        profilePda(maker.publicKey)
+      );
+      expect(profile.activeListings).to.equal(0);
+      expect(profile.listingsCancelled.toNumber()).to.equal(3);
+    });
+
+    it("creates and cancels a full batch in one transaction", async () => {
+      await fund(mintX, maker.publicKey, 10_000_000);
+      const ids = Array.from({ length: 10 }, () => nextListingId++);
+      const batch = ids.map((id) => listingPda(maker.publicKey, id));
+
+      await sendWithLookupTable(
+        maker,
+        await createListingsBatchBuilder(ids, batch).instruction()
+      );
+
+      for (const listing of batch) {
+        expect(await balance(mintX, listing)).to.equal(1_000_000);
+      }
+      expect(
+        (await program.account.userProfile.fetch(profilePda(maker.publicKey)))
+          .activeListings
+      ).to.equal(10);
+
+      await sendWithLookupTable(
+        maker,
+        await cancelAllListingsBuilder(maker, batch).instruction()
+      );
+
+      expect(await balance(mintX, maker.publicKey)).to.equal(13_000_000);
+      expect(
+        (await program.account.userProfile.fetch(profilePda(maker.publicKey)))
+          .activeListings
+      ).to.equal(0);
+    });
+
+    it("rejects a batch above the maximum", async () => {
+      const ids = Array.from({ length: 11 }, () => nextListingId++);
+
+      await expectError(
+        createListingsBatch(ids, []),
+        "InvalidListingBatch"
+      );
+    });
+  });
+
+  describe("cancel epoch", () => {
+    let mintS: PublicKey;
+    let mintD: PublicKey;
+    let maker: Keypair;
+    let taker: Keypair;
+    let staleListing: PublicKey;
+
+    before(async () => {
+      mintS = await newMint();
+      mintD = await newMint();
+      maker = await newUser();
+      taker = await newUser();
+      await fund(mintS, maker.publicKey, 10_000_000);
+      await fund(mintD, maker.publicKey, 0);
+      await fund(mintS, taker.publicKey, 0);
+      await fund(mintD, taker.publicKey, 10_000_000);
+      await fund(mintD, feeCollector.publicKey, 0);
+
+      staleListing = await createListing(
+        maker,
+        mintS,
+        mintD,
+        100_000,
+        100_000
+      );
+    });
+
+    const executeSwap = (
+      listing: PublicKey,
+      amount: number,
+      makerProfile: PublicKey | null = profilePda(maker.publicKey)
+    ) => swap(taker, maker, listing, amount, amount, makerProfile);
+
+    it("requires the maker profile for listings with an epoch", async () => {
+      const state = await program.account.listing.fetch(staleListing);
+      expect(state.cancelEpoch.toNumber()).to.equal(1);
+
+      await expectError(
+        executeSwap(staleListing, 50_000, null),
+        "UserProfileNotFound"
+      );
+    });
+
+    it("bumps the maker's cancel epoch", async () => {
+      await program.methods
+        .bumpCancelEpoch()
+        .accountsPartial({
+          user: maker.publicKey,
+          userProfile: profilePda(maker.publicKey),
+        })
+        .signers([maker])
+        .rpc();
+
+      const profile = await program.account.userProfile.fetch(
+        profilePda(maker.publicKey)
+      );
+      expect(profile.cancelEpoch.toNumber()).to.equal(2);
+    });
+
+    it("rejects a stale listing in execute_swap", async () => {
+      await expectError(
+        executeSwap(staleListing, 50_000),
+        "ListingCancelledByEpoch"
+      );
+    });
+
+    it("rejects a stale listing in execute_route", async () => {
+      await expectError(
+        executeRoute(taker, mintD, 50_000, 0, [
+          routeHop(taker, maker, staleListing, mintD, mintS),
+        ]),
+        "ListingCancelledByEpoch"
+      );
+    });
+
+    it("skips a stale listing in sweep_listings", async () => {
+      const fresh = await createListing(
+        maker,
+        mintS,
+        mintD,
+        100_000,
+        100_000
+      );
+      const takerS = await balance(mintS, taker.publicKey);
+
+      const signature = await sweepListings(
+        taker,
+        mintS,
+        mintD,
+        {
+          maxAmountIn: 50_000,
+          limitAmountSource: 1,
+          limitAmountDestination: 1,
+          minAmountOut: 0,
+        },
+        [
+          sweepListing(maker, staleListing, mintS, mintD),
+          sweepListing(maker, fresh, mintS, mintD),
+        ]
+      );
+
+      expect(await balance(mintS, taker.publicKey)).to.equal(takerS + 50_000);
+      const skipped = await eventsOf(signature, "SweepListingSkipped");
+      expect(skipped).to.have.length(1);
+      expect(skipped[0].listing.toBase58()).to.equal(staleListing.toBase58());
+      expect(skipped[0].reason).to.deep.equal({ cancelled: {} });
+    });
+
+    it("fills listings created after the bump", async () => {
+      const listing = await createListing(
+        maker,
+        mintS,
+        mintD,
+        100_000,
+        100_000
+      );
+      const takerS = await balance(mintS, taker.publicKey);
+
+      await executeSwap(listing, 50_000);
+
+      expect(await balance(mintS, taker.publicKey)).to.equal(takerS + 50_000);
+      const state = await program.account.listing.fetch(listing);
+      expect(state.cancelEpoch.toNumber()).to.equal(2);
+      expect(state.status).to.deep.equal({ partiallyFilled: {} });
+    });
+  });
+
+  describe("bid listings", () => {
+    let mintBase: PublicKey;
+    let mintQuote: PublicKey;
+    let maker: Keypair;
+    let taker: Keypair;
+
+    before(async () => {
+      mintBase = await newMint();
+      mintQuote = await newMint();
+      maker = await newUser();
+      taker = await newUser();
+      await fund(mintQuote, maker.publicKey, 2_000_000);
+      await fund(mintBase, maker.publicKey, 0);
+      await fund(mintQuote, taker.publicKey, 0);
+      await fund(mintBase, taker.publicKey, 1_000_000);
+      await fund(mintBase, feeCollector.publicKey, 0);
+    });
+
+    it("books the quote amount as volume for bid fills", async () => {
+      // The maker escrows 2 quote tokens per base token
+      const listing = await createListing(
+        maker,
+        mintQuote,
+        mintBase,
+        2_000_000,
+        1_000_000,
+        { side: { bid: {} } }
+      );
+      const platformBefore = await program.account.platform.fetch(platform);
+
+      await swap(taker, maker, listing, 1_000_000, 500_000);
+
+      // The taker receives quote tokens and the maker the base tokens
+      expect(await balance(mintQuote, taker.publicKey)).to.equal(1_000_000);
+      expect(await balance(mintBase, maker.publicKey)).to.equal(
+        500_000 - fee(500_000)
+      );
+
+      // Volume is the gross quote amount on every counter
+      const makerProfile = await program.account.userProfile.fetch(
+        profilePda(maker.publicKey)
+      );
+      const takerProfile = await program.account.userProfile.fetch(
+        profilePda(taker.publicKey)
+      );
+      const platformAfter = await program.account.platform.fetch(platform);
+      expect(makerProfile.volumeAsMaker.toNumber()).to.equal(1_000_000);
+      expect(makerProfile.swapsReceived.toNumber()).to.equal(1);
+      expect(takerProfile.volumeAsTaker.toNumber()).to.equal(1_000_000);
+      expect(
+        platformAfter.totalVolumeTraded
+          .sub(platformBefore.totalVolumeTraded)
+          .toNumber()
+      ).to.equal(1_000_000);
+      expect(
+        platformAfter.totalSwapsExecuted
+          .sub(platformBefore.totalSwapsExecuted)
+          .toNumber()
+      ).to.equal(1);
+    });
+  });
+
+  describe("offers", () => {
+    let mintS: PublicKey;
+    let mintD: PublicKey;
+    let maker: Keypair;
+    let taker: Keypair;
+    let nextOfferId = 1;
+
+    before(async () => {
+      mintS = await newMint();
+      mintD = await newMint();
+      maker = await newUser();
+      taker = await newUser();
+      await fund(mintS, maker.publicKey, 10_000_000);
+      await fund(mintD, maker.publicKey, 0);
+      await fund(mintS, taker.publicKey, 0);
+      await fund(mintD, taker.publicKey, 10_000_000);
+      await fund(mintD, feeCollector.publicKey, 0);
+    });
+
+    const offerPda = (listing: PublicKey, owner: PublicKey, id: number) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("offer"),
+          listing.toBuffer(),
+          owner.toBuffer(),
+          new BN(id).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+
+    const lamports = (account: PublicKey) => connection.getBalance(account);
+
+    async function makeOffer(
+      listing: PublicKey,
+      mintDestination: PublicKey,
+      amountSource: number,
+      amountDestination: number
+    ): Promise<PublicKey> {
+      const id = nextOfferId++;
+      const offer = offerPda(listing, taker.publicKey, id);
+      const native = mintDestination.equals(NATIVE_MINT);
+
+      await program.methods
+        .makeOffer({
+          id: new BN(id),
+          amountSource: new BN(amountSource),
+          amountDestination: new BN(amountDestination),
+          durationSeconds: LISTING_DURATION,
+        })
+        .accountsPartial({
+          taker: taker.publicKey,
+          platform,
+          listing,
+          offer,
+          offerVault: ata(mintDestination, offer),
+          takerTokenAccountSource: ata(mintS, taker.publicKey),
+          takerTokenAccountDestination: native
+            ? null
+            : ata(mintDestination, taker.publicKey),
+          tokenMintSource: mintS,
+          tokenMintDestination: mintDestination,
+          tokenProgramSource: TOKEN_PROGRAM_ID,
+          tokenProgramDestination: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+
+      return offer;
+    }
+
+    const acceptOffer = (
+      listing: PublicKey,
+      offer: PublicKey,
+      mintDestination: PublicKey
+    ) => {
+      const native = mintDestination.equals(NATIVE_MINT);
+      return program.methods
+        .acceptOffer()
+        .accountsPartial({
+          maker: maker.publicKey,
+          makerProfile: profilePda(maker.publicKey),
+          taker: taker.publicKey,
+          takerProfile: null,
+          platform,
+          feeCollector: feeCollector.publicKey,
+          listing,
+          vault: ata(mintS, listing),
+          offer,
+          offerVault: ata(mintDestination, offer),
+          takerTokenAccountSource: ata(mintS, taker.publicKey),
+          makerTokenAccountDestination: native
+            ? null
+            : ata(mintDestination, maker.publicKey),
+          feeCollectorTokenAccount: native
+            ? null
+            : ata(mintDestination, feeCollector.publicKey),
+          tokenMintSource: mintS,
+          tokenMintDestination: mintDestination,
+          tokenProgramSource: TOKEN_PROGRAM_ID,
+          tokenProgramDestination: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+    };
+
+    it("escrows the proposed payment", async () => {
+      const listing = await createListing(
+        maker,
+        mintS,
+        mintD,
+        1_000_000,
+        1_000_000
+      );
+      const takerBefore = await balance(mintD, taker.publicKey);
+
+      const offer = await makeOffer(listing, mintD, 500_000, 400_000);
+
+      expect(await balance(mintD, offer)).to.equal(400_000);
+      expect(await balance(mintD, taker.publicKey)).to.equal(
+        takerBefore - 400_000
+      );
+      const state = await program.account.offer.fetch(offer);
+      expect(state.amountSource.toNumber()).to.equal(500_000);
+      expect(state.amountDestination.toNumber()).to.equal(400_000);
+      expect(state.maker.toBase58()).to.equal(maker.publicKey.toBase58());
+    });
+
+    it("settles an accepted offer at the taker's price", async () => {
+      const listing = await createListing(
+        maker,
+        mintS,
+        mintD,
+        1_000_000,
+        1_000_000
+      );
+      const offer = await makeOffer(listing, mintD, 500_000, 400_000);
+      const makerBefore = await balance(mintD, maker.publicKey);
+      const collectorBefore = await balance(mintD, feeCollector.publicKey);
+      const takerSourceBefore = await balance(mintS, taker.publicKey);
+
+      await acceptOffer(listing, offer, mintD);
+
+      expect(await balance(mintS, taker.publicKey)).to.equal(
+        takerSourceBefore + 500_000
+      );
+      expect(await balance(mintD, maker.publicKey)).to.equal(
+        makerBefore + 400_000 - fee(400_000)
+      );
+      expect(await balance(mintD, feeCollector.publicKey)).to.equal(
+        collectorBefore + fee(400_000)
+      );
+      expect(await program.account.offer.fetchNullable(offer)).to.equal(null);
+      expect(await connection.getAccountInfo(ata(mintD, offer))).to.equal(
+        null
+      );
+
+      const state = await program.account.listing.fetch(listing);
+      expect(state.amountSourceRemaining.toNumber()).to.equal(500_000);
+      expect(state.status).to.deep.equal({ partiallyFilled: {} });
+    });
+
+    it("settles a native SOL offer and refunds the escrow rent", async () => {
+      const listing = await createListing(
+        maker,
+        mintS,
+        NATIVE_MINT,
+        1_000_000,
+        1_000_000
+      );
+      const offer = await makeOffer(listing, NATIVE_MINT, 500_000, 400_000);
+      const offerVault = ata(NATIVE_MINT, offer);
+      const offerRent = await lamports(offer);
+      const vaultRent = (await lamports(offerVault)) - 400_000;
+      const makerBefore = await lamports(maker.publicKey);
+      const takerBefore = await lamports(taker.publicKey);
+      const collectorBefore = await lamports(feeCollector.publicKey);
+
+      await acceptOffer(listing, offer, NATIVE_MINT);
+
+      // The maker receives SOL minus the fee, the taker both rents back
+      expect((await lamports(maker.publicKey)) - makerBefore).to.equal(
+        400_000 - fee(400_000)
+      );
+      expect((await lamports(feeCollector.publicKey)) - collectorBefore).to.equal(
+        fee(400_000)
+      );
+      expect((await lamports(taker.publicKey)) - takerBefore).to.equal(
+        offerRent + vaultRent
+      );
+      expect(await connection.getAccountInfo(offerVault)).to.equal(null);
+    });
+
+    it("returns the escrow when the taker withdraws", async () => {
+      const listing = await createListing(
+        maker,
+        mintS,
+        mintD,
+        1_000_000,
+        1_000_000
+      );
+      const offer = await makeOffer(listing, mintD, 500_000, 400_000);
+      const takerBefore = await balance(mintD, taker.publicKey);
+
+      const signature = await program.methods
+        .withdrawOffer()
+        .accountsPartial({
+          taker: taker.publicKey,
+          offer,
+          offerVault: ata(mintD, offer),
+          takerTokenAccountDestination: ata(mintD, taker.publicKey),
+          tokenMintDestination: mintD,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([taker])
+        .rpc({ commitment: "confirmed" });
+
+      expect(await balance(mintD, taker.publicKey)).to.equal(
+        takerBefore + 400_000
+      );
+      expect(await program.account.offer.fetchNullable(offer)).to.equal(null);
+      const [withdrawn] = await eventsOf(signature, "OfferWithdrawn");
+      expect(withdrawn.amountReturned.toNumber()).to.equal(400_000);
+
+      // The listing is untouched
+      const state = await program.account.listing.fetch(listing);
+      expect(state.amountSourceRemaining.toNumber()).to.equal(1_000_000);
+    });
+  });
+
+  describe("top_up_listing / withdraw_from_listing", () => {
+    let mintDestination: PublicKey;
+    let maker: Keypair;
+
+    before(async () => {
+      mintDestination = await newMint();
+      maker = await newUser();
+    });
+
+    const lamports = (account: PublicKey) => connection.getBalance(account);
+
+    const resize = (
+      method: "topUpListing" | "withdrawFromListing",
+      listing: PublicKey,
+      mint: PublicKey,
+      amount: number
+    ) =>
+      program.methods[method](new BN(amount))
+        .accountsPartial({
+          maker: maker.publicKey,
+          platform,
+          listing,
+          vault: ata(mint, listing),
+          makerTokenAccountSource:
+            method === "topUpListing" && mint.equals(NATIVE_MINT)
+              ? null
+              : ata(mint, maker.publicKey),
+          tokenMintSource: mint,
+          tokenProgram: programOf(mint),
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+
+    async function remaining(listing: PublicKey) {
+      const state = await program.account.listing.fetch(listing);
+      return {
+        source: state.amountSourceRemaining.toNumber(),
+        destination: state.amountDestinationRemaining.toNumber(),
+      };
+    }
+
+    it("tops up and withdraws an SPL Token listing at the same price", async () => {
+      const mint = await newMint();
+      await fund(mint, maker.publicKey, 3_000_000);
+      const listing = await createListing(
+        maker,
+        mint,
+        mintDestination,
+        1_000_000,
+        2_000_000
+      );
+
+      await resize("topUpListing", listing, mint, 1_000_000);
+      expect(await remaining(listing)).to.deep.equal({
+        source: 2_000_000,
+        destination: 4_000_000,
+      });
+      expect(await balance(mint, listing)).to.equal(2_000_000);
+
+      await resize("withdrawFromListing", listing, mint, 500_000);
+      expect(await remaining(listing)).to.deep.equal({
+        source: 1_500_000,
+        destination: 3_000_000,
+      });
+      expect(await balance(mint, maker.publicKey)).to.equal(1_500_000);
+    });
+
+    it("books Token-2022 inventory net of transfer fees", async () => {
+      // 1% transfer fee
+      const mint = await newTransferFeeMint(100);
+      await fund(mint, maker.publicKey, 3_000_000);
+      const listing = await createListing(
+        maker,
+        mint,
+        mintDestination,
+        1_000_000,
+        1_000_000
+      );
+      const before = await remaining(listing);
+
+      await resize("topUpListing", listing, mint, 1_000_000);
+      const afterTopUp = await remaining(listing);
+      expect(afterTopUp.source - before.source).to.equal(990_000);
+
+      const makerBefore = await balance(mint, maker.publicKey);
+      await resize("withdrawFromListing", listing, mint, 500_000);
+      expect((await remaining(listing)).source).to.equal(
+        afterTopUp.source - 500_000
+      );
+      expect((await balance(mint, maker.publicKey)) - makerBefore).to.equal(
+        495_000
+      );
+    });
+
+    it("rejects a withdrawal that leaves only dust", async () => {
+      const mint = await newMint();
+      await fund(mint, maker.publicKey, 1_000_000);
+      const listing = await createListing(
+        maker,
+        mint,
+        mintDestination,
+        1_000_000,
+        1_000_000
+      );
+
+      await expectError(
+        resize("withdrawFromListing", listing, mint, 999_500),
+        "AmountTooSmall"
+      );
+    });
+
+    it("wraps and unwraps native SOL", async () => {
+      const listing = await createListing(
+        maker,
+        NATIVE_MINT,
+        mintDestination,
+        1_000_000,
+        1_000_000
+      );
+
+      // Top up straight from the maker's lamports
+      let makerBefore = await lamports(maker.publicKey);
+      await resize("topUpListing", listing, NATIVE_MINT, 500_000);
+      expect(makerBefore - (await lamports(maker.publicKey))).to.equal(
+        500_000
+      );
+      expect(await balance(NATIVE_MINT, listing)).to.equal(1_500_000);
+
+      // Without a wSOL account, the withdrawal unwraps to lamports
+      makerBefore = await lamports(maker.publicKey);
+      await resize("withdrawFromListing", listing, NATIVE_MINT, 200_000);
+      expect((await lamports(maker.publicKey)) - makerBefore).to.equal(
+        200_000
+      );
+      expect(
+        await connection.getAccountInfo(ata(NATIVE_MINT, maker.publicKey))
+      ).to.equal(null);
+      expect((await remaining(listing)).source).to.equal(1_300_000);
+    });
+
+    it("leaves wSOL the maker already held wrapped", async () => {
+      const listing = await createListing(
+        maker,
+        NATIVE_MINT,
+        mintDestination,
+        1_000_000,
+        1_000_000
+      );
+
+      // The maker holds 300_000 wSOL of their own
+      const wsol = await fund(NATIVE_MINT, maker.publicKey, 0);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: maker.publicKey,
+            toPubkey: wsol,
+            lamports: 300_000,
+          }),
+          createSyncNativeInstruction(wsol)
+        ),
+        [maker]
+      );
+
+      await resize("withdrawFromListing", listing, NATIVE_MINT, 200_000);
+
+      expect(await balance(NATIVE_MINT, maker.publicKey)).to.equal(500_000);
+      expect((await remaining(listing)).source).to.equal(800_000);
+    });
+  });
+
+  describe("fill_rfq", () => {
+    const MAX_RFQ_QUOTE_SLOTS = 150;
+    const RFQ_QUOTE_DOMAIN = Buffer.from("selix:rfq_quote:v1");
+
+    let mintS: PublicKey;
+    let mintD: PublicKey;
+    let maker: Keypair;
+    let taker: Keypair;
+    let nextNonce = 0;
+
+    const [orderAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("order_authority")],
+      program.programId
+    );
+    const orderNoncesPda = (owner: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("order_nonces"), owner.toBuffer()],
+        program.programId
+      )[0];
+
+    before(async () => {
+      mintS = await newMint();
+      mintD = await newMint();
+      maker = await newUser();
+      taker = await newUser();
+      const makerSource = await fund(mintS, maker.publicKey, 10_000_000);
+      await fund(mintS, taker.publicKey, 0);
+      await fund(mintD, taker.publicKey, 10_000_000);
+      await fund(mintD, feeCollector.publicKey, 0);
+
+      await program.methods
+        .initializeOrderNonces()
+        .accountsPartial({
+          maker: maker.publicKey,
+          orderNonces: orderNoncesPda(maker.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      await approve(
+        connection,
+        payer,
+        makerSource,
+        orderAuthority,
+        maker,
+        10_000_000
+      );
+    });
+
+    // Quote for 100_000 source tokens at 2 destination tokens each
+    const newQuote = async (overrides: object = {}) => ({
+      platform,
+      maker: maker.publicKey,
+      taker: taker.publicKey,
+      nonce: new BN(nextNonce++),
+      side: { ask: {} },
+      tokenMintSource: mintS,
+      tokenMintDestination: mintD,
+      amountSource: new BN(100_000),
+      amountDestination: new BN(200_000),
+      expiresAtSlot: new BN((await connection.getSlot()) + 20),
+      ...overrides,
+    });
+
+    const signQuote = (quote: object, signer: Keypair) =>
+      Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: signer.secretKey,
+        message: Buffer.concat([
+          RFQ_QUOTE_DOMAIN,
+          program.coder.types.encode("rfqQuote", quote),
+        ]),
+      });
+
+    const fillRfq = (
+      quote: any,
+      {
+        signer = maker,
+        filler = taker,
+        signed = true,
+      }: { signer?: Keypair; filler?: Keypair; signed?: boolean } = {}
+    ) =>
+      program.methods
+        .fillRfq(quote)
+        .accountsPartial({
+          taker: filler.publicKey,
+          takerProfile: profilePda(filler.publicKey),
+          maker: maker.publicKey,
+          makerProfile: profilePda(maker.publicKey),
+          orderNonces: orderNoncesPda(maker.publicKey),
+          platform,
+          feeCollector: feeCollector.publicKey,
+          orderAuthority,
+          makerTokenAccountSource: ata(mintS, maker.publicKey),
+          makerTokenAccountDestination: ata(mintD, maker.publicKey),
+          takerTokenAccountSource: ata(mintS, filler.publicKey),
+          takerTokenAccountDestination: ata(mintD, filler.publicKey),
+          feeCollectorTokenAccount: ata(mintD, feeCollector.publicKey),
+          tokenMintSource: mintS,
+          tokenMintDestination: mintD,
+          sourceWhitelist: null,
+          destWhitelist: null,
+          tokenProgramSource: TOKEN_PROGRAM_ID,
+          tokenProgramDestination: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions(signed ? [signQuote(quote, signer)] : [])
+        .signers([filler])
+        .rpc();
+
+    it("fills a quote signed by the maker", async () => {
+      const quote = await newQuote();
+      const takerS = await balance(mintS, taker.publicKey);
+      const collectorD = await balance(mintD, feeCollector.publicKey);
+
+      const signature = await fillRfq(quote);
+
+      expect(await balance(mintS, taker.publicKey)).to.equal(takerS + 100_000);
+      expect(await balance(mintD, maker.publicKey)).to.equal(
+        200_000 - fee(200_000)
+      );
+      expect(await balance(mintD, feeCollector.publicKey)).to.equal(
+        collectorD + fee(200_000)
+      );
+
+      const [filled] = await eventsOf(signature, "RfqFilled");
+      expect(filled.nonce.toNumber()).to.equal(quote.nonce.toNumber());
+      expect(filled.feeAmount.toNumber()).to.equal(fee(200_000));
+    });
+
+    it("rejects a second quote with a used nonce", async () => {
+      const nonce = new BN(nextNonce++);
+      await fillRfq(await newQuote({ nonce }));
+
+      await expectError(
+        fillRfq(
+          await newQuote({ nonce, amountDestination: new BN(210_000) })
+        ),
+        "NonceAlreadyUsed"
+      );
+    });
+
+    it("rejects an expired quote", async () => {
+      const slot = await connection.getSlot();
+
+      await expectError(
+        fillRfq(await newQuote({ expiresAtSlot: new BN(slot - 1) })),
+        "QuoteExpired"
+      );
+    });
+
+    it("rejects a quote valid for more than MAX_RFQ_QUOTE_SLOTS", async () => {
+      const slot = await connection.getSlot();
+
+      await expectError(
+        fillRfq(
+          await newQuote({
+            expiresAtSlot: new BN(slot + MAX_RFQ_QUOTE_SLOTS + 50),
+          })
+        ),
+        "QuoteValidityTooLong"
+      );
+    });
+
+    it("rejects a quote signed by another key", async () => {
+      await expectError(
+        fillRfq(await newQuote(), { signer: Keypair.generate() }),
+        "SignatureMismatch"
+      );
+    });
+
+    it("rejects a quote without the Ed25519 instruction", async () => {
+      await expectError(
+        fillRfq(await newQuote(), { signed: false }),
+        "InvalidSignatureInstruction"
+      );
+    });
+
+    it("rejects a quote filled by another taker", async () => {
+      const other = await newUser();
+      await fund(mintD, other.publicKey, 1_000_000);
+
+      await expectError(
+        fillRfq(await newQuote(), { filler: other }),
+        "TakerNotAllowed"
+      );
+    });
+  });
+
+  describe("native SOL swaps", () => {
+    let mint: PublicKey;
+    let maker: Keypair;
+    let taker: Keypair;
+
+    const lamports = (owner: PublicKey) => connection.getBalance(owner);
+
+    before(async () => {
+      mint = await newMint();
+      maker = await newUser();
+      taker = await newUser();
+      await fund(mint, maker.publicKey, 10_000_000);
+      await fund(mint, taker.publicKey, 10_000_000);
+      await fund(mint, feeCollector.publicKey, 0);
+    });
+
+    it("pays a native SOL destination from the taker's wallet", async () => {
+      const listing = await createListing(
+        maker,
+        mint,
+        NATIVE_MINT,
+        1_000_000,
+        500_000
+      );
+      const takerSol = await lamports(taker.publicKey);
+      const makerSol = await lamports(maker.publicKey);
+      const collectorSol = await lamports(feeCollector.publicKey);
+      const takerTokens = await balance(mint, taker.publicKey);
+
+      await swap(taker, maker, listing, 1_000_000, 500_000);
+
+      expect(await balance(mint, taker.publicKey)).to.equal(
+        takerTokens + 1_000_000
+      );
+      expect(await lamports(taker.publicKey)).to.equal(takerSol - 500_000);
+      expect(await lamports(maker.publicKey)).to.equal(
+        makerSol + 500_000 - fee(500_000)
+      );
+      expect(await lamports(feeCollector.publicKey)).to.equal(
+        collectorSol + fee(500_000)
+      );
+
+      // Nothing was wrapped for the destination leg
+      expect(
+        await connection.getAccountInfo(ata(NATIVE_MINT, taker.publicKey))
+      ).to.equal(null);
+      expect(
+        await connection.getAccountInfo(ata(NATIVE_MINT, maker.publicKey))
+      ).to.equal(null);
+    });
+
+    it("rejects a native SOL fill the taker cannot pay", async () => {
+      const listing = await createListing(
+        maker,
+        mint,
+        NATIVE_MINT,
+        1_000_000,
+        20 * LAMPORTS_PER_SOL
+      );
+
+      await expectError(
+        swap(taker, maker, listing, 1_000_000, 20 * LAMPORTS_PER_SOL),
+        "InsufficientTakerBalance"
+      );
+    });
+
+    it("unwraps a native SOL source to the taker", async () => {
+      const listing = await createListing(
+        maker,
+        NATIVE_MINT,
+        mint,
+        1_000_000,
+        2_000_000
+      );
+      const takerSol = await lamports(taker.publicKey);
+
+      await swap(taker, maker, listing, 1_000_000, 2_000_000);
+
+      // The temporary wSOL account is closed and its rent refunded
+      expect(await lamports(taker.publicKey)).to.equal(takerSol + 1_000_000);
+      expect(
+        await connection.getAccountInfo(ata(NATIVE_MINT, taker.publicKey))
+      ).to.equal(null);
+      expect(await balance(mint, maker.publicKey)).to.equal(
+        8_000_000 + 2_000_000 - fee(2_000_000)
+      );
+    });
+  });
+
+  describe("all-or-none listings", () => {
+    let mintS: PublicKey;
+    let mintD: PublicKey;
+    let maker: Keypair;
+    let taker: Keypair;
+
+    before(async () => {
+      mintS = await newMint();
+      mintD = await newMint();
+      maker = await newUser();
+      taker = await newUser();
+      await fund(mintS, maker.publicKey, 1_000_000);
+      await fund(mintS, taker.publicKey, 0);
+      await fund(mintD, taker.publicKey, 10_000_000);
+      await fund(mintD, feeCollector.publicKey, 0);
+    });
+
+    it("rejects partial fills and settles the whole listing", async () => {
+      const listing = await createListing(
+        maker,
+        mintS,
+        mintD,
+        1_000_000,
+        2_000_000,
+        { allOrNone: true }
+      );
+
+      await expectError(
+        swap(taker, maker, listing, 500_000, 1_000_000),
+        "PartialFillNotAllowed"
+      );
+      expect(await balance(mintS, listing)).to.equal(1_000_000);
+
+      await swap(taker, maker, listing, 1_000_000, 2_000_000);
+
+      expect(await balance(mintS, taker.publicKey)).to.equal(1_000_000);
+      const state = await program.account.listing.fetch(listing);
+      expect(state.status).to.deep.equal({ completed: {} });
+      expect(state.amountSourceRemaining.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("activate_listing", () => {
+    let mintS: PublicKey;
+    let mintD: PublicKey;
+    let maker: Keypair;
+    let taker: Keypair;
+
+    // Unix timestamp of the on-chain clock
+    const chainTime = async () => {
+      const clock = await connection.getAccountInfo(SYSVAR_CLOCK_PUBKEY);
+      return Number(clock.data.readBigInt64LE(32));
+    };
+
+    const activateListing = (listing: PublicKey) =>
+      program.methods
+        .activateListing()
+        .accountsPartial({ caller: taker.publicKey, platform, listing })
+        .signers([taker])
+        .rpc();
+
+    before(async () => {
+      mintS = await newMint();
+      mintD = await newMint();
+      maker = await newUser();
+      taker = await newUser();
+      await fund(mintS, maker.publicKey, 1_000_000);
+      await fund(mintS, taker.publicKey, 0);
+      await fund(mintD, taker.publicKey, 10_000_000);
+      await fund(mintD, feeCollector.publicKey, 0);
+    });
+
+    it("keeps a scheduled listing pending until its start time", async () => {
+      const startsAt = (await chainTime()) + 5;
+      const listing = await createListing(
+        maker,
+        mintS,
+        mintD,
+        1_000_000,
+        1_000_000,
+        { startsAt: new BN(startsAt) }
+      );
+      let state = await program.account.listing.fetch(listing);
+      expect(state.status).to.deep.equal({ pending: {} });
+
+      await expectError(activateListing(listing), "ListingNotStarted");
+      await expectError(
+        swap(taker, maker, listing, 500_000, 500_000),
+        "ListingNotStarted"
+      );
+
+      while ((await chainTime()) < startsAt) {
+        await new Promise((resolve) => setTimeout(resolve, 500));
+      }
+      const signature = await activateListing(listing);
+
+      state = await program.account.listing.fetch(listing);
+      expect(state.status).to.deep.equal({ active: {} });
+      const [activated] = await eventsOf(signature, "ListingActivated");
+      expect(activated.activatedBy.toBase58()).to.equal(
+        taker.publicKey.toBase58()
+      );
+
+      await swap(taker, maker, listing, 500_000, 500_000);
+      expect(await balance(mintS, taker.publicKey)).to.equal(500_000);
+    });
+  });
+
+  describe("mixed token programs", () => {
+    let mintLegacy: PublicKey;
+    let mint2022: PublicKey;
+    let maker: Keypair;
+    let taker: Keypair;
+
+    before(async () => {
+      mintLegacy = await newMint();
+      mint2022 = await newMint(TOKEN_2022_PROGRAM_ID);
+      maker = await newUser();
+      taker = await newUser();
+      await fund(mint2022, maker.publicKey, 1_000_000);
+      await fund(mint2022, taker.publicKey, 0);
+      await fund(mintLegacy, taker.publicKey, 10_000_000);
+      await fund(mintLegacy, feeCollector.publicKey, 0);
+    });
+
+    it("trades a Token-2022 mint against an SPL Token mint", async () => {
+      const listing = await createListing(
+        maker,
+        mint2022,
+        mintLegacy,
+        1_000_000,
+        2_000_000
+      );
+
+      // Each vault and token account lives under its own mint's program
+      const vault = await connection.getAccountInfo(ata(mint2022, listing));
+      expect(vault.owner.toBase58()).to.equal(TOKEN_2022_PROGRAM_ID.toBase58());
+
+      await swap(taker, maker, listing, 1_000_000, 2_000_000);
+
+      expect(await balance(mint2022, taker.publicKey)).to.equal(1_000_000);
+      expect(await balance(mintLegacy, maker.publicKey)).to.equal(
+        2_000_000 - fee(2_000_000)
+      );
+      const makerAccount = await connection.getAccountInfo(
+        ata(mintLegacy, maker.publicKey)
+      );
+      expect(makerAccount.owner.toBase58()).to.equal(
+        TOKEN_PROGRAM_ID.toBase58()
+      );
+    });
   });
 });